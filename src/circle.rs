@@ -0,0 +1,16 @@
+use crate::Geometry;
+
+/// A circle computed by [`maximum_inscribed_circle`](crate::Geom::maximum_inscribed_circle),
+/// [`largest_empty_circle`](crate::Geom::largest_empty_circle) or
+/// [`minimum_bounding_circle`](crate::Geom::minimum_bounding_circle).
+#[derive(Debug, Clone)]
+pub struct Circle {
+    /// The geometry returned by GEOS: a `LineString` going from the center to the nearest point
+    /// of the input for the inscribed and empty circles, a `Polygon` approximating the circle for
+    /// the bounding circle.
+    pub geometry: Geometry,
+    /// The center of the circle, as a `Point`.
+    pub center: Geometry,
+    /// The radius of the circle.
+    pub radius: f64,
+}
@@ -0,0 +1,188 @@
+use crate::context_handle::with_context;
+use crate::error::{Error, GResult};
+use crate::functions::nullcheck;
+use crate::traits::as_raw_mut_impl;
+use crate::{AsRaw, AsRawMut};
+use geos_sys::*;
+use std::ptr::NonNull;
+
+/// The result of a clustering operation.
+///
+/// Inputs are the components of the clustered geometry, identified by their index (the one you
+/// would pass to [`Geom::get_geometry_n`](crate::Geom::get_geometry_n)).
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry};
+///
+/// let geom = Geometry::new_from_wkt(
+///     "MULTIPOINT ((0 0), (1 0), (0 1), (10 10), (11 10), (10 11), (50 50))",
+/// )?;
+/// let clusters = geom.cluster_dbscan(1.5, 2)?;
+///
+/// assert_eq!(clusters.get_num_clusters(), 2);
+///
+/// let ids = clusters.get_clusters_for_inputs()?;
+/// assert!(ids[0].is_some());
+/// assert!(ids[0] == ids[1] && ids[1] == ids[2]);
+/// assert!(ids[3] == ids[4] && ids[4] == ids[5]);
+/// assert_ne!(ids[0], ids[3]);
+/// // The last point is too far from the others: it is noise.
+/// assert_eq!(ids[6], None);
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub struct ClusterInfo {
+    ptr: NonNull<GEOSClusterInfo>,
+    num_inputs: usize,
+}
+
+impl ClusterInfo {
+    pub(crate) const fn new_from_raw(ptr: NonNull<GEOSClusterInfo>, num_inputs: usize) -> Self {
+        Self { ptr, num_inputs }
+    }
+
+    /// Returns the number of clusters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION (POLYGON ((0 0, 1 0, 1 1, 0 0)), \
+    ///                          POLYGON ((1 0, 2 0, 2 1, 1 0)), \
+    ///                          POINT (10 10))",
+    /// )?;
+    /// let clusters = geom.cluster_geometry_intersects()?;
+    ///
+    /// assert_eq!(clusters.get_num_clusters(), 2);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn get_num_clusters(&self) -> usize {
+        with_context(|ctx| unsafe { GEOSClusterInfo_getNumClusters_r(ctx.as_raw(), self.as_raw()) })
+    }
+
+    /// Returns the number of inputs which were clustered.
+    pub const fn get_num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the number of inputs in the `n`th cluster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT ((0 0), (1 0), (2 0), (10 10))")?;
+    /// let clusters = geom.cluster_geometry_distance(1.5)?;
+    ///
+    /// let mut sizes = (0..clusters.get_num_clusters())
+    ///     .map(|n| clusters.get_cluster_size(n))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// sizes.sort();
+    /// assert_eq!(sizes, vec![1, 3]);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn get_cluster_size(&self, n: usize) -> GResult<usize> {
+        self.check_cluster_index(n)?;
+        with_context(|ctx| unsafe {
+            Ok(GEOSClusterInfo_getClusterSize_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                n,
+            ))
+        })
+    }
+
+    /// Returns the indices of the inputs belonging to the `n`th cluster.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT ((0 0), (10 10), (0 1))")?;
+    /// let clusters = geom.cluster_envelope_distance(2.)?;
+    ///
+    /// let ids = clusters.get_clusters_for_inputs()?;
+    /// let mut inputs = clusters.get_inputs_for_cluster_n(ids[0].unwrap())?;
+    /// inputs.sort();
+    /// assert_eq!(inputs, vec![0, 2]);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn get_inputs_for_cluster_n(&self, n: usize) -> GResult<Vec<usize>> {
+        let size = self.get_cluster_size(n)?;
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+        with_context(|ctx| unsafe {
+            let ptr: NonNull<usize> = nullcheck!(GEOSClusterInfo_getInputsForClusterN_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                n
+            ))?;
+            // The array is owned by the `GEOSClusterInfo`, it must not be freed here.
+            Ok(std::slice::from_raw_parts(ptr.as_ptr(), size).to_vec())
+        })
+    }
+
+    /// Returns, for each input, the index of the cluster it belongs to. Inputs which don't belong
+    /// to any cluster (the noise points of [`cluster_dbscan`](crate::Geom::cluster_dbscan)) are
+    /// [`None`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT ((0 0), (0.5 0), (5 5))")?;
+    /// let clusters = geom.cluster_envelope_intersects()?;
+    ///
+    /// let ids = clusters.get_clusters_for_inputs()?;
+    /// assert_eq!(ids.len(), 3);
+    /// assert!(ids.iter().all(Option::is_some));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn get_clusters_for_inputs(&self) -> GResult<Vec<Option<usize>>> {
+        if self.num_inputs == 0 {
+            return Ok(Vec::new());
+        }
+        with_context(|ctx| unsafe {
+            // Value used by GEOS for inputs which don't belong to any cluster.
+            let cluster_none = GEOS_CLUSTER_NONE;
+            let ptr: NonNull<usize> = nullcheck!(GEOSClusterInfo_getClustersForInputs_r(
+                ctx.as_raw(),
+                self.as_raw()
+            ))?;
+            let ids = std::slice::from_raw_parts(ptr.as_ptr(), self.num_inputs)
+                .iter()
+                .map(|&id| (id != cluster_none).then_some(id))
+                .collect();
+            GEOSFree_r(ctx.as_raw(), ptr.as_ptr().cast());
+            Ok(ids)
+        })
+    }
+
+    fn check_cluster_index(&self, n: usize) -> GResult<()> {
+        let num_clusters = self.get_num_clusters();
+        if n >= num_clusters {
+            return Err(Error::GenericError(format!(
+                "cluster index {n} is out of bounds (there are {num_clusters} clusters)"
+            )));
+        }
+        Ok(())
+    }
+}
+
+unsafe impl Send for ClusterInfo {}
+unsafe impl Sync for ClusterInfo {}
+
+impl Drop for ClusterInfo {
+    fn drop(&mut self) {
+        with_context(|ctx| unsafe { GEOSClusterInfo_destroy_r(ctx.as_raw(), self.as_raw_mut()) });
+    }
+}
+
+as_raw_mut_impl!(ClusterInfo, GEOSClusterInfo);
@@ -7,6 +7,10 @@ use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::ptr::NonNull;
 use std::slice;
+#[cfg(feature = "v3_14_0")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "v3_14_0")]
+use std::sync::Arc;
 use std::sync::Mutex;
 
 thread_local!(
@@ -31,6 +35,11 @@ pub fn with_context<R>(f: impl FnOnce(&ContextHandle) -> R) -> R {
 
 pub type HandlerCallback = Box<dyn Fn(&str) + Send + Sync>;
 
+/// A callback regularly called by long-running GEOS operations, which are interrupted as soon as
+/// it returns `true`.
+#[cfg(feature = "v3_14_0")]
+pub type InterruptCallback = Arc<dyn Fn() -> bool + Send + Sync>;
+
 unsafe extern "C" fn message_handler(message: *const c_char, data: *mut c_void) {
     let inner_context: &InnerContext = &*(data.cast());
 
@@ -56,10 +65,51 @@ impl InnerContext {
     }
 }
 
+#[cfg(feature = "v3_14_0")]
+unsafe extern "C" fn interrupt_handler(data: *mut c_void) -> libc::c_int {
+    let interrupt_context: &InterruptContext = &*(data.cast());
+
+    // GEOS polls this handler very often, so the lock is only taken when a callback is set. The
+    // operations run by the callback itself aren't interrupted, to avoid recursing forever.
+    if !interrupt_context.has_callback.load(Ordering::Relaxed)
+        || interrupt_context.in_callback.swap(true, Ordering::Relaxed)
+    {
+        return 0;
+    }
+    // The lock is released before calling the callback, since it may run GEOS operations.
+    let callback = interrupt_context
+        .callback
+        .lock()
+        .ok()
+        .and_then(|callback| callback.clone());
+    let interrupt = callback.map_or(false, |callback| callback());
+    interrupt_context
+        .in_callback
+        .store(false, Ordering::Relaxed);
+    if interrupt {
+        interrupt_context.interrupted.store(true, Ordering::Relaxed);
+    }
+    interrupt.into()
+}
+
+#[cfg(feature = "v3_14_0")]
+pub struct InterruptContext {
+    /// Set when the callback asked GEOS to interrupt the current operation, so the error it
+    /// raises can be reported as [`Error::Interrupted`].
+    interrupted: AtomicBool,
+    /// Whether `callback` is set, so it can be checked without locking.
+    has_callback: AtomicBool,
+    /// Set while `callback` is running.
+    in_callback: AtomicBool,
+    callback: Mutex<Option<InterruptCallback>>,
+}
+
 pub struct ContextHandle {
     ptr: NonNull<GEOSContextHandle_HS>,
     pub(crate) notice_ctx: NonNull<InnerContext>,
     pub(crate) error_ctx: NonNull<InnerContext>,
+    #[cfg(feature = "v3_14_0")]
+    pub(crate) interrupt_ctx: NonNull<InterruptContext>,
 }
 
 impl ContextHandle {
@@ -91,6 +141,16 @@ impl ContextHandle {
             })))
         };
 
+        #[cfg(feature = "v3_14_0")]
+        let interrupt_ctx = unsafe {
+            NonNull::new_unchecked(Box::into_raw(Box::new(InterruptContext {
+                interrupted: AtomicBool::new(false),
+                has_callback: AtomicBool::new(false),
+                in_callback: AtomicBool::new(false),
+                callback: Mutex::new(None),
+            })))
+        };
+
         unsafe {
             GEOSContext_setNoticeMessageHandler_r(
                 ptr.as_ptr(),
@@ -102,12 +162,20 @@ impl ContextHandle {
                 Some(message_handler),
                 error_ctx.as_ptr().cast(),
             );
+            #[cfg(feature = "v3_14_0")]
+            GEOSContext_setInterruptCallback_r(
+                ptr.as_ptr(),
+                Some(interrupt_handler),
+                interrupt_ctx.as_ptr().cast(),
+            );
         }
 
         Ok(Self {
             ptr,
             notice_ctx,
             error_ctx,
+            #[cfg(feature = "v3_14_0")]
+            interrupt_ctx,
         })
     }
 
@@ -123,6 +191,26 @@ impl ContextHandle {
         unsafe { self.error_ctx.as_ref() }
     }
 
+    #[cfg(feature = "v3_14_0")]
+    pub(crate) fn get_interrupt_context(&self) -> &InterruptContext {
+        unsafe { self.interrupt_ctx.as_ref() }
+    }
+
+    /// Builds the error returned when `caller` failed: [`Error::Interrupted`] if the operation
+    /// was interrupted, an [`Error::GeosError`] holding the last error message otherwise.
+    pub(crate) fn take_error(&self, caller: &'static str) -> Error {
+        let message = self.get_last_error();
+        #[cfg(feature = "v3_14_0")]
+        if self
+            .get_interrupt_context()
+            .interrupted
+            .swap(false, Ordering::Relaxed)
+        {
+            return Error::Interrupted;
+        }
+        Error::GeosError((caller, message))
+    }
+
     /// Allows to set a notice message handler.
     ///
     /// Passing [`None`] as parameter will unset this callback.
@@ -163,6 +251,60 @@ impl ContextHandle {
         }
     }
 
+    /// Allows to set a callback which is regularly called by long-running operations. As soon as
+    /// it returns `true`, the current operation is stopped and returns [`Error::Interrupted`].
+    ///
+    /// Passing [`None`] as parameter will unset this callback. It returns the previous callback.
+    ///
+    /// The callback must not panic. The GEOS operations it runs itself are never interrupted. See
+    /// [`with_interrupt`](crate::with_interrupt) to only interrupt the operations run in a given
+    /// scope.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::ContextHandle;
+    /// use std::sync::Arc;
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let context_handle = ContextHandle::init()?;
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(10);
+    /// context_handle.set_interrupt_callback(Some(Arc::new(move || Instant::now() > deadline)));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    pub fn set_interrupt_callback(
+        &self,
+        callback: Option<InterruptCallback>,
+    ) -> Option<InterruptCallback> {
+        let interrupt_context = self.get_interrupt_context();
+        interrupt_context
+            .interrupted
+            .store(false, Ordering::Relaxed);
+        match interrupt_context.callback.lock() {
+            Ok(mut current) => {
+                interrupt_context
+                    .has_callback
+                    .store(callback.is_some(), Ordering::Relaxed);
+                std::mem::replace(&mut *current, callback)
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the current interrupt callback.
+    #[cfg(feature = "v3_14_0")]
+    pub(crate) fn interrupt_callback(&self) -> Option<InterruptCallback> {
+        self.get_interrupt_context()
+            .callback
+            .lock()
+            .ok()
+            .and_then(|callback| callback.clone())
+    }
+
     /// Returns the last error encountered.
     ///
     /// Please note that calling this function will remove the current last error!
@@ -306,6 +448,8 @@ impl Drop for ContextHandle {
             // Now we just have to clear stuff!
             let _ = Box::from_raw(self.error_ctx.as_ptr());
             let _ = Box::from_raw(self.notice_ctx.as_ptr());
+            #[cfg(feature = "v3_14_0")]
+            let _ = Box::from_raw(self.interrupt_ctx.as_ptr());
         }
     }
 }
@@ -626,6 +626,12 @@ impl CoordSeq {
         })
     }
 
+    /// Returns the layout of the coordinates stored in this sequence.
+    #[cfg(feature = "v3_10_0")]
+    pub(crate) const fn coord_type(&self) -> CoordType {
+        self.coord_type
+    }
+
     /// Gets the entire `CoordSeq` object as individual coordinate arrays.
     ///
     /// Returns a tuple with four vectors. The first and second vectors correspond to `x` and `y`
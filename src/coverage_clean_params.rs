@@ -0,0 +1,120 @@
+use crate::context_handle::with_context;
+use crate::functions::{errcheck, nullcheck};
+use crate::traits::as_raw_mut_impl;
+use crate::{AsRaw, AsRawMut, GResult, OverlapMergeStrategy};
+use geos_sys::*;
+use std::ptr::NonNull;
+
+/// Contains the parameters which describe how a polygonal coverage should be cleaned using
+/// [`coverage_clean_with_params`](crate::Geom::coverage_clean_with_params)
+pub struct CoverageCleanParams {
+    ptr: NonNull<GEOSCoverageCleanParams>,
+}
+
+/// Build options for a [`CoverageCleanParams`] object
+#[derive(Default)]
+pub struct CoverageCleanParamsBuilder {
+    snapping_distance: Option<f64>,
+    gap_maximum_width: Option<f64>,
+    overlap_merge_strategy: Option<OverlapMergeStrategy>,
+}
+
+impl CoverageCleanParams {
+    pub fn new() -> GResult<Self> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSCoverageCleanParams_create_r(ctx.as_raw()))?;
+            Ok(Self { ptr })
+        })
+    }
+
+    pub fn builder() -> CoverageCleanParamsBuilder {
+        CoverageCleanParamsBuilder::default()
+    }
+
+    /// Sets the distance below which vertices and edges are snapped together.
+    ///
+    /// A negative value lets GEOS compute a distance from the extent of the input. `0` disables
+    /// snapping.
+    pub fn set_snapping_distance(&mut self, snapping_distance: f64) -> GResult<()> {
+        with_context(|ctx| unsafe {
+            errcheck!(GEOSCoverageCleanParams_setSnappingDistance_r(
+                ctx.as_raw(),
+                self.as_raw_mut_override(),
+                snapping_distance,
+            ))?;
+            Ok(())
+        })
+    }
+
+    /// Sets the maximum width of the gaps which are merged into an adjacent polygon.
+    ///
+    /// Gaps wider than this are left untouched. Default is `0`, meaning no gap is merged.
+    pub fn set_gap_maximum_width(&mut self, gap_maximum_width: f64) -> GResult<()> {
+        with_context(|ctx| unsafe {
+            errcheck!(GEOSCoverageCleanParams_setGapMaximumWidth_r(
+                ctx.as_raw(),
+                self.as_raw_mut_override(),
+                gap_maximum_width,
+            ))?;
+            Ok(())
+        })
+    }
+
+    /// Sets the strategy used to pick the polygon an overlap is merged into.
+    ///
+    /// Default is [`OverlapMergeStrategy::LongestBorder`].
+    pub fn set_overlap_merge_strategy(&mut self, strategy: OverlapMergeStrategy) -> GResult<()> {
+        with_context(|ctx| unsafe {
+            errcheck!(GEOSCoverageCleanParams_setOverlapMergeStrategy_r(
+                ctx.as_raw(),
+                self.as_raw_mut_override(),
+                strategy.into(),
+            ))?;
+            Ok(())
+        })
+    }
+}
+
+unsafe impl Send for CoverageCleanParams {}
+unsafe impl Sync for CoverageCleanParams {}
+
+impl Drop for CoverageCleanParams {
+    fn drop(&mut self) {
+        with_context(|ctx| unsafe {
+            GEOSCoverageCleanParams_destroy_r(ctx.as_raw(), self.as_raw_mut());
+        });
+    }
+}
+
+as_raw_mut_impl!(CoverageCleanParams, GEOSCoverageCleanParams);
+
+impl CoverageCleanParamsBuilder {
+    pub const fn snapping_distance(mut self, snapping_distance: f64) -> Self {
+        self.snapping_distance = Some(snapping_distance);
+        self
+    }
+
+    pub const fn gap_maximum_width(mut self, gap_maximum_width: f64) -> Self {
+        self.gap_maximum_width = Some(gap_maximum_width);
+        self
+    }
+
+    pub const fn overlap_merge_strategy(mut self, strategy: OverlapMergeStrategy) -> Self {
+        self.overlap_merge_strategy = Some(strategy);
+        self
+    }
+
+    pub fn build(self) -> GResult<CoverageCleanParams> {
+        let mut params = CoverageCleanParams::new()?;
+        if let Some(snapping_distance) = self.snapping_distance {
+            params.set_snapping_distance(snapping_distance)?;
+        }
+        if let Some(gap_maximum_width) = self.gap_maximum_width {
+            params.set_gap_maximum_width(gap_maximum_width)?;
+        }
+        if let Some(strategy) = self.overlap_merge_strategy {
+            params.set_overlap_merge_strategy(strategy)?;
+        }
+        Ok(params)
+    }
+}
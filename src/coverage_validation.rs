@@ -0,0 +1,66 @@
+use crate::error::GResult;
+use crate::{ConstGeometry, Geom, Geometry};
+
+/// The result of a [`coverage_is_valid`](crate::Geom::coverage_is_valid) call.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry};
+///
+/// let geom = Geometry::new_from_wkt(
+///     "GEOMETRYCOLLECTION (POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0)), \
+///                          POLYGON ((9 0, 20 0, 20 10, 9 10, 9 0)), \
+///                          POLYGON ((30 0, 40 0, 40 10, 30 10, 30 0)))",
+/// )?;
+/// let validation = geom.coverage_is_valid(0.)?;
+///
+/// assert!(!validation.is_valid());
+///
+/// let invalid = validation.invalid_polygons()?;
+/// let indices: Vec<usize> = invalid.iter().map(|(index, _)| *index).collect();
+/// assert_eq!(indices, vec![0, 1]);
+/// # Ok::<(), geos::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct CoverageValidation {
+    is_valid: bool,
+    invalid_edges: Geometry,
+}
+
+impl CoverageValidation {
+    pub(crate) const fn new(is_valid: bool, invalid_edges: Geometry) -> Self {
+        Self {
+            is_valid,
+            invalid_edges,
+        }
+    }
+
+    /// Returns `true` if the input is a valid polygonal coverage.
+    pub const fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    /// Returns the invalid edges, as a collection containing one linear geometry per input
+    /// polygon. The element of a polygon is empty if none of its edges are invalid.
+    pub const fn invalid_edges(&self) -> &Geometry {
+        &self.invalid_edges
+    }
+
+    /// Returns the index of each input polygon having invalid edges, along with those edges.
+    pub fn invalid_polygons(&self) -> GResult<Vec<(usize, ConstGeometry<'_>)>> {
+        let mut invalid = Vec::new();
+        for n in 0..self.invalid_edges.get_num_geometries()? {
+            let edges = self.invalid_edges.get_geometry_n(n)?;
+            if !edges.is_empty()? {
+                invalid.push((n, edges));
+            }
+        }
+        Ok(invalid)
+    }
+
+    /// Consumes the result and returns the invalid edges.
+    pub fn into_invalid_edges(self) -> Geometry {
+        self.invalid_edges
+    }
+}
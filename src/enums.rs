@@ -1,6 +1,7 @@
 use libc::{c_int, size_t};
 
 use std::convert::TryFrom;
+use std::ops::{BitOr, BitOrAssign};
 
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 pub enum CoordDimensions {
@@ -150,6 +151,43 @@ impl Into<c_int> for ByteOrder {
     }
 }
 
+/// Flavors of WKB written by a [`WKBWriter`](crate::WKBWriter)
+#[cfg(feature = "v3_10_0")]
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum WkbFlavor {
+    /// The PostGIS extended WKB (EWKB), which can store the SRID and flags Z/M dimensions with
+    /// the high bits of the geometry type.
+    #[default]
+    Extended,
+    /// The ISO WKB, which encodes Z/M dimensions by adding 1000, 2000 or 3000 to the geometry
+    /// type.
+    Iso,
+}
+
+#[cfg(feature = "v3_10_0")]
+impl TryFrom<c_int> for WkbFlavor {
+    type Error = crate::error::Error;
+
+    fn try_from(flavor: c_int) -> Result<Self, Self::Error> {
+        match flavor {
+            1 => Ok(Self::Extended),
+            2 => Ok(Self::Iso),
+            _ => Err(Self::Error::GenericError("Unknown WKB flavor".into())),
+        }
+    }
+}
+
+#[cfg(feature = "v3_10_0")]
+#[allow(clippy::from_over_into)]
+impl Into<c_int> for WkbFlavor {
+    fn into(self) -> c_int {
+        match self {
+            Self::Extended => 1,
+            Self::Iso => 2,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 #[repr(C)]
 pub enum GeometryTypes {
@@ -494,6 +532,90 @@ impl Into<u32> for MakeValidMethod {
     }
 }
 
+/// Meaning of the parameter of a [`Geometry`](crate::Geometry)
+/// [`polygon_hull_simplify`](crate::Geom::polygon_hull_simplify) operation
+#[cfg(feature = "v3_11_0")]
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum PolygonHullMode {
+    /// The parameter is the fraction of the input vertices kept in the hull.
+    #[default]
+    VertexRatio,
+    /// The parameter is the ratio between the area of the input and the area of the hull.
+    AreaRatio,
+}
+
+#[cfg(feature = "v3_11_0")]
+impl TryFrom<u32> for PolygonHullMode {
+    type Error = crate::error::Error;
+
+    fn try_from(mode: u32) -> Result<Self, Self::Error> {
+        match mode {
+            1 => Ok(Self::VertexRatio),
+            2 => Ok(Self::AreaRatio),
+            _ => Err(Self::Error::GenericError(
+                "Unknown polygon hull mode".into(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "v3_11_0")]
+#[allow(clippy::from_over_into)]
+impl Into<u32> for PolygonHullMode {
+    fn into(self) -> u32 {
+        match self {
+            Self::VertexRatio => 1,
+            Self::AreaRatio => 2,
+        }
+    }
+}
+
+/// Strategies used to merge overlaps in a [`Geometry`](crate::Geometry)
+/// [`coverage_clean_with_params`](crate::Geom::coverage_clean_with_params) operation
+#[cfg(feature = "v3_14_0")]
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum OverlapMergeStrategy {
+    /// Merges the overlap into the adjacent polygon sharing the longest border with it.
+    #[default]
+    LongestBorder,
+    /// Merges the overlap into the adjacent polygon with the largest area.
+    MaxArea,
+    /// Merges the overlap into the adjacent polygon with the smallest area.
+    MinArea,
+    /// Merges the overlap into the adjacent polygon with the smallest index in the input.
+    MinIndex,
+}
+
+#[cfg(feature = "v3_14_0")]
+impl TryFrom<c_int> for OverlapMergeStrategy {
+    type Error = crate::error::Error;
+
+    fn try_from(strategy: c_int) -> Result<Self, Self::Error> {
+        match strategy {
+            0 => Ok(Self::LongestBorder),
+            1 => Ok(Self::MaxArea),
+            2 => Ok(Self::MinArea),
+            3 => Ok(Self::MinIndex),
+            _ => Err(Self::Error::GenericError(
+                "Unknown overlap merge strategy".into(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "v3_14_0")]
+#[allow(clippy::from_over_into)]
+impl Into<c_int> for OverlapMergeStrategy {
+    fn into(self) -> c_int {
+        match self {
+            Self::LongestBorder => 0,
+            Self::MaxArea => 1,
+            Self::MinArea => 2,
+            Self::MinIndex => 3,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 pub enum DimensionType {
     Point,
@@ -513,3 +635,93 @@ impl TryFrom<c_int> for DimensionType {
         }
     }
 }
+
+/// Flags changing how a [`Geometry`](crate::Geometry) is checked by
+/// [`is_valid_detail`](crate::Geom::is_valid_detail). They can be combined with `|`.
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash)]
+pub struct ValidFlags(c_int);
+
+impl ValidFlags {
+    /// Uses the OGC validity rules.
+    pub const NONE: Self = Self(0);
+    /// Considers as valid the rings which self-touch to form a hole (ESRI validity model).
+    pub const ALLOW_SELFTOUCHING_RING_FORMING_HOLE: Self = Self(1);
+
+    /// Returns `true` if all the flags of `other` are set in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for ValidFlags {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ValidFlags {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<c_int> for ValidFlags {
+    fn into(self) -> c_int {
+        self.0
+    }
+}
+
+/// Rules deciding which points of a linear geometry are on its boundary, used by
+/// [`relate_boundary_node_rule`](crate::Geom::relate_boundary_node_rule)
+#[derive(Default, Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum BoundaryNodeRule {
+    /// Endpoints are on the boundary if they occur an odd number of times. This is the OGC rule.
+    #[default]
+    Mod2,
+    /// All endpoints are on the boundary.
+    Endpoint,
+    /// Endpoints are on the boundary if they occur more than once.
+    MultivalentEndpoint,
+    /// Endpoints are on the boundary if they occur only once.
+    MonovalentEndpoint,
+}
+
+impl TryFrom<c_int> for BoundaryNodeRule {
+    type Error = crate::error::Error;
+
+    fn try_from(rule: c_int) -> Result<Self, Self::Error> {
+        match rule {
+            1 => Ok(Self::Mod2),
+            2 => Ok(Self::Endpoint),
+            3 => Ok(Self::MultivalentEndpoint),
+            4 => Ok(Self::MonovalentEndpoint),
+            _ => Err(Self::Error::GenericError(
+                "Unknown boundary node rule".into(),
+            )),
+        }
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl Into<c_int> for BoundaryNodeRule {
+    fn into(self) -> c_int {
+        match self {
+            Self::Mod2 => 1,
+            Self::Endpoint => 2,
+            Self::MultivalentEndpoint => 3,
+            Self::MonovalentEndpoint => 4,
+        }
+    }
+}
+
+/// Topological locations of a point relative to a geometry, indexing the rows and columns of an
+/// [`IntersectionMatrix`](crate::IntersectionMatrix)
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+pub enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
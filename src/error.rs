@@ -6,6 +6,9 @@ pub enum Error {
     ImpossibleOperation(String),
     ConversionError(String),
     GenericError(String),
+    /// The operation was stopped by an interrupt callback, see
+    /// [`ContextHandle::set_interrupt_callback`](crate::ContextHandle::set_interrupt_callback).
+    Interrupted,
 }
 
 impl std::error::Error for Error {}
@@ -18,6 +21,7 @@ impl fmt::Display for Error {
             Self::ImpossibleOperation(ref s) => write!(f, "impossible operation: {s}"),
             Self::ConversionError(ref s) => write!(f, "impossible to convert geometry: {s}"),
             Self::GenericError(ref s) => write!(f, "{s}"),
+            Self::Interrupted => write!(f, "operation interrupted"),
         }
     }
 }
@@ -45,7 +45,7 @@ macro_rules! nullcheck {
     ($func:ident($ctx:ident.as_raw() $(, $($args:expr),* $(,)?)?)) => {{
         let result = $func($ctx.as_raw()$(, $($args),*)?);
         std::ptr::NonNull::new(result as *mut _).ok_or_else(|| {
-            $crate::Error::GeosError((stringify!($func), $ctx.get_last_error()))
+            $ctx.take_error(stringify!($func))
         })
     }};
 }
@@ -54,7 +54,7 @@ macro_rules! errcheck {
     ($errval:expr, $func:ident($ctx:ident.as_raw() $(, $($args:expr),* $(,)?)?)) => {{
         let result = $func($ctx.as_raw()$(, $($args),*)?);
         if result == $errval {
-            Err($crate::Error::GeosError((stringify!($func), $ctx.get_last_error())))
+            Err($ctx.take_error(stringify!($func)))
         } else {
             Ok(result)
         }
@@ -82,6 +82,7 @@ pub fn create_multi_geom(
     mut geoms: Vec<Geometry>,
     output_type: GeometryTypes,
 ) -> GResult<Geometry> {
+    let has_user_data = geoms.iter().any(|g| g.has_user_data);
     let nb_geoms = geoms.len();
     let res = {
         let mut geoms: Vec<*mut GEOSGeometry> =
@@ -103,7 +104,10 @@ pub fn create_multi_geom(
         std::mem::forget(g);
     }
 
-    res
+    res.map(|mut collection| {
+        collection.has_user_data = has_user_data;
+        collection
+    })
 }
 
 pub fn orientation_index(
@@ -158,3 +162,108 @@ pub fn segment_intersection(
         Ok((ret != -1).then_some((cx, cy)))
     })
 }
+
+/// Sorts `geometries` by the [Hilbert code](Geom::hilbert_code) of the center of their envelope,
+/// computed at the given `level` over the envelope of all the geometries. Empty geometries are
+/// moved to the end. The sort is stable.
+///
+/// Sorting geometries this way before writing them or before building a
+/// [`STRtree`](crate::STRtree) keeps the ones close to each other together.
+///
+/// Available using the `v3_11_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{sort_by_hilbert_code, Geom, Geometry};
+///
+/// let mut geoms = vec![
+///     Geometry::new_from_wkt("POINT (10 10)")?,
+///     Geometry::new_from_wkt("POINT EMPTY")?,
+///     Geometry::new_from_wkt("POINT (0 0)")?,
+/// ];
+/// sort_by_hilbert_code(&mut geoms, 16)?;
+///
+/// assert_eq!(geoms[0].to_wkt()?, "POINT (0 0)");
+/// assert!(geoms[2].is_empty()?);
+/// # Ok::<(), geos::Error>(())
+/// ```
+#[cfg(feature = "v3_11_0")]
+pub fn sort_by_hilbert_code<G: Geom>(geometries: &mut [G], level: u32) -> GResult<()> {
+    sort_by_hilbert_code_key(geometries, level, |geom| geom)
+}
+
+/// Sorts `records` by the [Hilbert code](Geom::hilbert_code) of the center of the envelope of
+/// the geometry returned by `key`, like [`sort_by_hilbert_code`].
+///
+/// Available using the `v3_11_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{sort_by_hilbert_code_key, Geometry};
+///
+/// let mut records = vec![
+///     (Geometry::new_from_wkt("POINT (10 10)")?, "far"),
+///     (Geometry::new_from_wkt("POINT (0 0)")?, "origin"),
+/// ];
+/// sort_by_hilbert_code_key(&mut records, 16, |(geom, _)| geom)?;
+///
+/// assert_eq!(records[0].1, "origin");
+/// # Ok::<(), geos::Error>(())
+/// ```
+#[cfg(feature = "v3_11_0")]
+pub fn sort_by_hilbert_code_key<T, G: Geom, F: Fn(&T) -> &G>(
+    records: &mut [T],
+    level: u32,
+    key: F,
+) -> GResult<()> {
+    let mut bounds: Option<(f64, f64, f64, f64)> = None;
+    let mut non_empty = Vec::with_capacity(records.len());
+    for record in records.iter() {
+        let geom = key(record);
+        let is_empty = geom.is_empty()?;
+        if !is_empty {
+            let (x_min, y_min) = (geom.get_x_min()?, geom.get_y_min()?);
+            let (x_max, y_max) = (geom.get_x_max()?, geom.get_y_max()?);
+            bounds = Some(match bounds {
+                Some((a, b, c, d)) => (a.min(x_min), b.min(y_min), c.max(x_max), d.max(y_max)),
+                None => (x_min, y_min, x_max, y_max),
+            });
+        }
+        non_empty.push(!is_empty);
+    }
+    let Some((x_min, y_min, x_max, y_max)) = bounds else {
+        return Ok(());
+    };
+    let extent = Geometry::create_rectangle(x_min, y_min, x_max, y_max)?;
+
+    let mut codes = Vec::with_capacity(records.len());
+    for (record, non_empty) in records.iter().zip(non_empty) {
+        codes.push(if non_empty {
+            Some(key(record).hilbert_code(&extent, level)?)
+        } else {
+            None
+        });
+    }
+
+    // Empty geometries have no code and are put last.
+    let mut order = (0..records.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| (codes[i].is_none(), codes[i]));
+
+    // Moves each record to its sorted position, one cycle of the permutation at a time.
+    let mut placed = vec![false; order.len()];
+    for start in 0..order.len() {
+        let mut position = start;
+        while !placed[position] {
+            placed[position] = true;
+            let source = order[position];
+            if source == start {
+                break;
+            }
+            records.swap(position, source);
+            position = source;
+        }
+    }
+    Ok(())
+}
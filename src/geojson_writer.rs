@@ -25,6 +25,8 @@ use std::ptr::NonNull;
 /// ```
 pub struct GeoJSONWriter {
     ptr: NonNull<GEOSGeoJSONWriter>,
+    #[cfg(feature = "v3_12_0")]
+    rfc7946: bool,
 }
 
 impl GeoJSONWriter {
@@ -47,7 +49,11 @@ impl GeoJSONWriter {
     pub fn new() -> GResult<Self> {
         with_context(|ctx| unsafe {
             let ptr = nullcheck!(GEOSGeoJSONWriter_create_r(ctx.as_raw()))?;
-            Ok(Self { ptr })
+            Ok(Self {
+                ptr,
+                #[cfg(feature = "v3_12_0")]
+                rfc7946: false,
+            })
         })
     }
 
@@ -72,6 +78,15 @@ impl GeoJSONWriter {
     }
 
     pub fn write_formatted<G: Geom>(&mut self, geometry: &G, indent: i32) -> GResult<String> {
+        #[cfg(feature = "v3_12_0")]
+        if self.rfc7946 {
+            let oriented = geometry.orient_polygons(false)?;
+            return self.write_geometry(&oriented, indent);
+        }
+        self.write_geometry(geometry, indent)
+    }
+
+    fn write_geometry<G: Geom>(&mut self, geometry: &G, indent: i32) -> GResult<String> {
         with_context(|ctx| unsafe {
             let ptr = nullcheck!(GEOSGeoJSONWriter_writeGeometry_r(
                 ctx.as_raw(),
@@ -83,6 +98,40 @@ impl GeoJSONWriter {
         })
     }
 
+    /// Sets whether the polygons should be written following the
+    /// [RFC 7946](https://www.rfc-editor.org/rfc/rfc7946#section-3.1.6) winding order: exterior
+    /// rings counter-clockwise and interior rings clockwise. By default, it is `false` and rings are
+    /// written as they are.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{GeoJSONWriter, Geometry};
+    ///
+    /// let clockwise = Geometry::new_from_wkt("POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))")?;
+    /// let counter_clockwise = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))")?;
+    /// let mut writer = GeoJSONWriter::new()?;
+    /// let expected = writer.write(&counter_clockwise)?;
+    /// assert_ne!(writer.write(&clockwise)?, expected);
+    ///
+    /// writer.set_rfc7946(true);
+    /// assert_eq!(writer.write(&clockwise)?, expected);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    pub fn set_rfc7946(&mut self, rfc7946: bool) {
+        self.rfc7946 = rfc7946;
+    }
+
+    /// Returns whether the polygons are written following the RFC 7946 winding order. By
+    /// default, it is `false`.
+    #[cfg(feature = "v3_12_0")]
+    pub fn get_rfc7946(&self) -> bool {
+        self.rfc7946
+    }
+
     /// Sets the number of dimensions to be used when calling [`GeoJSONWriter::write`]. By default, it
     /// is 3.
     ///
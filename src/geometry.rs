@@ -1,20 +1,38 @@
-use crate::context_handle::with_context;
+use crate::context_handle::{with_context, ContextHandle};
 use crate::enums::*;
 use crate::error::{Error, GResult};
 use crate::functions::*;
 use crate::traits::{as_raw_impl, as_raw_mut_impl};
+#[cfg(feature = "v3_8_0")]
+use crate::Circle;
+#[cfg(feature = "v3_14_0")]
+use crate::ClusterInfo;
+#[cfg(feature = "v3_14_0")]
+use crate::CoverageCleanParams;
+#[cfg(feature = "v3_12_0")]
+use crate::CoverageValidation;
 #[cfg(feature = "v3_10_0")]
 use crate::GeoJSONWriter;
+#[cfg(feature = "v3_14_0")]
+use crate::GridSpec;
 #[cfg(feature = "v3_10_0")]
 use crate::MakeValidParams;
 #[cfg(feature = "v3_6_0")]
 use crate::Precision;
-use crate::{AsRaw, AsRawMut, BufferParams, CoordSeq, PreparedGeometry, WKTWriter};
+use crate::{
+    AsRaw, AsRawMut, BufferParams, CoordSeq, IntersectionMatrix, PolygonizeResult,
+    PreparedGeometry, ValidityDetail, WKTReader, WKTWriter,
+};
 use geos_sys::*;
+use std::any::Any;
 use std::borrow::Borrow;
+use std::collections::HashSet;
 use std::ffi::CString;
+use std::fmt;
 use std::marker::PhantomData;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
 use std::{self, str};
 
 /// Representation of a GEOS geometry.
@@ -31,6 +49,9 @@ use std::{self, str};
 /// ```
 pub struct Geometry {
     pub(crate) ptr: NonNull<GEOSGeometry>,
+    /// Set when data may be attached to `self` or to one of its parts with
+    /// [`Geometry::set_user_data`], so that only those geometries are walked when dropped.
+    pub(crate) has_user_data: bool,
 }
 
 // Representation of a GEOS geometry. Since it's only a view over another GEOS geometry data,
@@ -121,6 +142,54 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns why the geometry is invalid and where the problem is located, or `None` if the
+    /// geometry is valid. `flags` allows to relax the validity rules.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, ValidFlags};
+    ///
+    /// // Bowtie polygon with self-intersection
+    /// let geom = Geometry::new_from_wkt("POLYGON((0 0, 2 2, 2 0, 0 2, 0 0))")?;
+    /// let detail = geom.is_valid_detail(ValidFlags::NONE)?.unwrap();
+    /// assert_eq!(detail.reason, "Self-intersection");
+    /// assert_eq!(detail.location.unwrap().to_wkt()?, "POINT (1 1)");
+    ///
+    /// // Shell touching itself to form a hole
+    /// let geom = Geometry::new_from_wkt(
+    ///     "POLYGON ((0 0, 0 10, 10 10, 10 0, 5 0, 3 3, 5 6, 7 3, 5 0, 0 0))",
+    /// )?;
+    /// assert!(geom.is_valid_detail(ValidFlags::NONE)?.is_some());
+    /// assert!(geom
+    ///     .is_valid_detail(ValidFlags::ALLOW_SELFTOUCHING_RING_FORMING_HOLE)?
+    ///     .is_none());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn is_valid_detail(&self, flags: ValidFlags) -> GResult<Option<ValidityDetail>> {
+        let mut reason: *mut libc::c_char = ::std::ptr::null_mut();
+        let mut location: *mut GEOSGeometry = ::std::ptr::null_mut();
+
+        with_context(|ctx| unsafe {
+            let is_valid = predicate!(GEOSisValidDetail_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                flags.into(),
+                &mut reason,
+                &mut location,
+            ))?;
+            if is_valid {
+                return Ok(None);
+            }
+            let location = NonNull::new(location).map(Geometry::new_from_raw);
+            let reason = match NonNull::new(reason) {
+                Some(reason) => managed_string(reason, ctx)?,
+                None => String::new(),
+            };
+            Ok(Some(ValidityDetail { reason, location }))
+        })
+    }
+
     /// Get the underlying geos `CoordSeq` object from the geometry
     ///
     /// Note: this clones the underlying `CoordSeq` to avoid double free
@@ -552,6 +621,60 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Same as [`relate`](Geom::relate), but returns a parsed [`IntersectionMatrix`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON ((0 0, 2 0, 2 2, 0 2, 0 0))")?;
+    /// let geom2 = Geometry::new_from_wkt("POINT (1 1)")?;
+    ///
+    /// let matrix = geom1.relate_matrix(&geom2)?;
+    /// assert!(matrix.is_contains());
+    /// assert!(matrix.matches("T*****FF*")?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn relate_matrix<G: Geom>(&self, other: &G) -> GResult<IntersectionMatrix> {
+        self.relate(other)?.parse()
+    }
+
+    /// Computes the [`IntersectionMatrix`] of `self` and `other`, using `rule` to decide which
+    /// endpoints of linear geometries are on their boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{BoundaryNodeRule, Geom, Geometry, Location};
+    ///
+    /// // A closed line has no boundary with the OGC rule.
+    /// let ring = Geometry::new_from_wkt("LINESTRING (0 0, 1 0, 1 1, 0 0)")?;
+    /// let point = Geometry::new_from_wkt("POINT (0 0)")?;
+    ///
+    /// let matrix = ring.relate_boundary_node_rule(&point, BoundaryNodeRule::Mod2)?;
+    /// assert!(matrix.get(Location::Interior, Location::Interior).is_some());
+    ///
+    /// let matrix = ring.relate_boundary_node_rule(&point, BoundaryNodeRule::Endpoint)?;
+    /// assert!(matrix.get(Location::Boundary, Location::Interior).is_some());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn relate_boundary_node_rule<G: Geom>(
+        &self,
+        other: &G,
+        rule: BoundaryNodeRule,
+    ) -> GResult<IntersectionMatrix> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSRelateBoundaryNodeRule_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                other.as_raw(),
+                rule.into(),
+            ))?;
+            managed_string(ptr, ctx)?.parse()
+        })
+    }
+
     /// Returns a geometry which represents all points whose distance from `self` is less than or
     /// equal to distance.
     ///
@@ -722,6 +845,49 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         with_context(|ctx| unsafe { predicate!(GEOSisSimple_r(ctx.as_raw(), self.as_raw())) })
     }
 
+    /// Same as [`is_simple`](Geom::is_simple), but also returns where the geometry is not
+    /// simple: a point, or a multipoint of all the non-simple locations if `find_all` is `true`.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, GeometryTypes};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING (0 0, 4 0, 4 2, 1 2, 1 -1, 3 -1, 3 3)")?;
+    ///
+    /// let (is_simple, location) = geom.is_simple_detail(false)?;
+    /// assert!(!is_simple);
+    /// assert_eq!(location.unwrap().geometry_type()?, GeometryTypes::Point);
+    ///
+    /// let (_, location) = geom.is_simple_detail(true)?;
+    /// assert_eq!(location.unwrap().get_num_geometries()?, 3);
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING (0 0, 1 1)")?;
+    /// let (is_simple, location) = geom.is_simple_detail(true)?;
+    /// assert!(is_simple);
+    /// assert!(location.is_none());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn is_simple_detail(&self, find_all: bool) -> GResult<(bool, Option<Geometry>)> {
+        let mut location: *mut GEOSGeometry = ::std::ptr::null_mut();
+
+        with_context(|ctx| unsafe {
+            let is_simple = predicate!(GEOSisSimpleDetail_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                find_all.into(),
+                &mut location,
+            ))?;
+            Ok((
+                is_simple,
+                NonNull::new(location).map(Geometry::new_from_raw),
+            ))
+        })
+    }
+
     /// Returns a geometry which represents part of `self` that doesn't intersect with `other`.
     ///
     /// # Example
@@ -950,6 +1116,158 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Checks whether the polygons of `self` form a valid polygonal coverage: they must not
+    /// overlap and adjacent polygons must share their vertices. If `gap_width` is greater than
+    /// `0`, gaps narrower than it are reported as invalid too.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION (POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0)), \
+    ///                          POLYGON ((10 0, 20 0, 20 10, 10 10, 10 0)))",
+    /// )?;
+    /// let validation = geom.coverage_is_valid(0.)?;
+    ///
+    /// assert!(validation.is_valid());
+    /// assert!(validation.invalid_polygons()?.is_empty());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn coverage_is_valid(&self, gap_width: f64) -> GResult<CoverageValidation> {
+        let mut invalid_edges: *mut GEOSGeometry = ::std::ptr::null_mut();
+
+        with_context(|ctx| unsafe {
+            let is_valid = predicate!(GEOSCoverageIsValid_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                gap_width,
+                &mut invalid_edges,
+            ))?;
+            let invalid_edges = NonNull::new(invalid_edges)
+                .ok_or_else(|| ctx.take_error("GEOSCoverageIsValid_r"))?;
+            Ok(CoverageValidation::new(
+                is_valid,
+                Geometry::new_from_raw(invalid_edges),
+            ))
+        })
+    }
+
+    /// Simplifies the polygonal coverage `self` using the Visvalingam-Whyatt algorithm, while
+    /// preserving its topology: shared edges are simplified identically so that no gap nor
+    /// overlap is introduced between adjacent polygons.
+    ///
+    /// `tolerance` is the square root of the area of the triangles which get removed. If
+    /// `preserve_boundary` is `true`, the outer boundary of the coverage is kept unchanged.
+    ///
+    /// The input must be a valid coverage (see [`coverage_is_valid`](Geom::coverage_is_valid)).
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION (POLYGON ((0 0, 5 0.1, 10 0, 10 10, 0 10, 0 0)), \
+    ///                          POLYGON ((10 0, 20 0, 20 10, 10 10, 10 0)))",
+    /// )?;
+    /// let simplified = geom.coverage_simplify_vw(1., false)?;
+    ///
+    /// assert_eq!(simplified.get_num_geometries()?, 2);
+    /// // The small bump on the bottom edge was removed.
+    /// assert_eq!(simplified.get_geometry_n(0)?.get_num_coordinates()?, 5);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn coverage_simplify_vw(&self, tolerance: f64, preserve_boundary: bool) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSCoverageSimplifyVW_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                tolerance,
+                preserve_boundary.into(),
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Cleans the polygonal coverage `self` using the default parameters, so that it can be
+    /// processed by coverage operations like [`coverage_union`](Geom::coverage_union).
+    ///
+    /// Overlaps are merged into an adjacent polygon and the polygons are snapped together. The
+    /// output contains the same number of polygons as the input, in the same order.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION (POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0)), \
+    ///                          POLYGON ((9 0, 20 0, 20 10, 9 10, 9 0)))",
+    /// )?;
+    /// let cleaned = geom.coverage_clean()?;
+    ///
+    /// assert_eq!(cleaned.get_num_geometries()?, 2);
+    /// assert_eq!(cleaned.coverage_union()?.area()?, 200.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn coverage_clean(&self) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSCoverageClean_r(ctx.as_raw(), self.as_raw()))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Cleans the polygonal coverage `self` using the provided parameters.
+    ///
+    /// This allows control over the snapping distance, the maximum width of the gaps to fill and
+    /// how overlaps are merged.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{CoverageCleanParams, Geom, Geometry, OverlapMergeStrategy};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "GEOMETRYCOLLECTION (POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0)), \
+    ///                          POLYGON ((9 0, 12 0, 12 10, 9 10, 9 0)))",
+    /// )?;
+    ///
+    /// let params = CoverageCleanParams::builder()
+    ///     .overlap_merge_strategy(OverlapMergeStrategy::MaxArea)
+    ///     .build()?;
+    ///
+    /// let cleaned = geom.coverage_clean_with_params(&params)?;
+    ///
+    /// // The overlap is given to the largest polygon.
+    /// assert_eq!(cleaned.get_geometry_n(0)?.area()?, 100.);
+    /// assert_eq!(cleaned.get_geometry_n(1)?.area()?, 20.);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn coverage_clean_with_params(&self, params: &CoverageCleanParams) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSCoverageCleanWithParams_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                params.as_raw(),
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
     /// Create a voronoi diagram.
     ///
     /// # Example
@@ -1847,6 +2165,38 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns a copy of `self` where the rings of every polygon are oriented consistently: the
+    /// exterior rings are clockwise if `exterior_cw` is `true`, counter-clockwise otherwise, and
+    /// the interior rings have the opposite orientation. Non-polygonal geometries are left
+    /// untouched.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))")?;
+    /// assert!(!geom.get_exterior_ring()?.get_coord_seq()?.is_ccw()?);
+    ///
+    /// let oriented = geom.orient_polygons(false)?;
+    /// assert!(oriented.get_exterior_ring()?.get_coord_seq()?.is_ccw()?);
+    /// assert_eq!(oriented.to_wkt()?, "POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn orient_polygons(&self, exterior_cw: bool) -> GResult<Geometry> {
+        let mut geom = Geom::clone(self)?;
+        with_context(|ctx| unsafe {
+            errcheck!(
+                -1,
+                GEOSOrientPolygons_r(ctx.as_raw(), geom.as_raw_mut(), exterior_cw.into())
+            )?;
+            Ok(geom)
+        })
+    }
+
     /// Returns the number of geometries.
     ///
     /// # Example
@@ -1893,6 +2243,30 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the data attached to `self` with [`Geometry::set_user_data`], or `None` if there
+    /// is none or if it isn't of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let mut point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+    /// assert_eq!(point_geom.user_data::<u64>(), None);
+    ///
+    /// point_geom.set_user_data(42u64);
+    /// assert_eq!(point_geom.user_data::<u64>(), Some(&42));
+    /// assert_eq!(point_geom.user_data::<String>(), None);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn user_data<T: Any>(&self) -> Option<&T> {
+        with_context(|ctx| unsafe {
+            get_user_data(ctx, self.as_raw())
+                .as_ref()
+                .and_then(|data| data.downcast_ref())
+        })
+    }
+
     /// Returns the precision of `self`.
     ///
     /// Available using the `v3_6_0` feature.
@@ -2035,6 +2409,42 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the code of the center of the envelope of `self` on a Hilbert curve of the given
+    /// `level` (between 0 and 16), filling the envelope of `extent`.
+    ///
+    /// Geometries close to each other tend to have close codes, which makes the code a good
+    /// sorting key to improve spatial locality. See also [`sort_by_hilbert_code`](crate::sort_by_hilbert_code).
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let extent = Geometry::create_rectangle(0., 0., 16., 16.)?;
+    /// let point = Geometry::new_from_wkt("POINT (0 0)")?;
+    /// assert_eq!(point.hilbert_code(&extent, 4)?, 0);
+    ///
+    /// let point = Geometry::new_from_wkt("POINT (10 12)")?;
+    /// assert!(point.hilbert_code(&extent, 4)? < 256);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_11_0")]
+    fn hilbert_code<G: Geom>(&self, extent: &G, level: u32) -> GResult<u32> {
+        with_context(|ctx| unsafe {
+            let mut code = 0;
+            errcheck!(GEOSHilbertCode_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                extent.as_raw(),
+                level,
+                &mut code
+            ))?;
+            Ok(code)
+        })
+    }
+
     /// Returns the smallest distance by which a vertex of `self` could be moved to produce an
     /// invalid geometry.
     ///
@@ -2109,45 +2519,187 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
-    /// Returns a [delaunay triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation)
-    /// around the vertices of `self`.
+    /// Returns the largest circle contained in the polygonal geometry `self`. Its center is the
+    /// pole of inaccessibility, a good anchor for labels.
+    ///
+    /// The center is computed up to `tolerance`.
+    ///
+    /// Available using the `v3_9_0` feature.
     ///
     /// # Example
     ///
     /// ```
     /// use geos::{Geom, Geometry};
     ///
-    /// let geom1 = Geometry::new_from_wkt("POLYGON((175 150, 20 40, 50 60, 125 100, 175 150))")?;
-    /// let geom2 = Geometry::new_from_wkt("POINT(110 170)")?;
-    /// let geom2 = geom2.buffer(20., 8)?;
-    ///
-    /// let geom = geom1.union(&geom2)?;
+    /// let geom = Geometry::new_from_wkt("POLYGON ((0 0, 10 0, 10 4, 0 4, 0 0))")?;
+    /// let circle = geom.maximum_inscribed_circle(0.01)?;
     ///
-    /// let final_geom = geom.delaunay_triangulation(0.001, false)?;
+    /// assert!((circle.radius - 2.).abs() < 0.01);
+    /// assert!((circle.center.get_y()? - 2.).abs() < 0.01);
     /// # Ok::<(), geos::Error>(())
     /// ```
-    fn delaunay_triangulation(&self, tolerance: f64, only_edges: bool) -> GResult<Geometry> {
-        with_context(|ctx| unsafe {
-            let ptr = nullcheck!(GEOSDelaunayTriangulation_r(
+    #[cfg(feature = "v3_9_0")]
+    fn maximum_inscribed_circle(&self, tolerance: f64) -> GResult<Circle> {
+        let geometry = with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSMaximumInscribedCircle_r(
                 ctx.as_raw(),
                 self.as_raw(),
-                tolerance,
-                only_edges.into(),
+                tolerance
             ))?;
             Ok(Geometry::new_from_raw(ptr))
+        })?;
+        Ok(Circle {
+            center: geometry.get_start_point()?,
+            radius: geometry.length()?,
+            geometry,
         })
     }
 
-    fn interpolate(&self, d: f64) -> GResult<Geometry> {
-        with_context(|ctx| unsafe {
-            let ptr = nullcheck!(GEOSInterpolate_r(ctx.as_raw(), self.as_raw(), d))?;
+    /// Returns the largest circle whose center is inside `boundary` and whose interior doesn't
+    /// intersect `self`, the obstacles. If `boundary` is empty, the convex hull of `self` is used.
+    ///
+    /// The center is computed up to `tolerance`.
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let obstacles = Geometry::new_from_wkt("MULTIPOINT ((0 0), (10 0), (10 10), (0 10))")?;
+    /// let boundary = Geometry::create_empty_polygon()?;
+    /// let circle = obstacles.largest_empty_circle(&boundary, 0.01)?;
+    ///
+    /// assert!((circle.center.get_x()? - 5.).abs() < 0.01);
+    /// assert!((circle.center.get_y()? - 5.).abs() < 0.01);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_9_0")]
+    fn largest_empty_circle<G: Geom>(&self, boundary: &G, tolerance: f64) -> GResult<Circle> {
+        let geometry = with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSLargestEmptyCircle_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                boundary.as_raw(),
+                tolerance
+            ))?;
             Ok(Geometry::new_from_raw(ptr))
+        })?;
+        Ok(Circle {
+            center: geometry.get_start_point()?,
+            radius: geometry.length()?,
+            geometry,
         })
     }
 
-    fn interpolate_normalized(&self, d: f64) -> GResult<Geometry> {
-        with_context(|ctx| unsafe {
-            let ptr = nullcheck!(GEOSInterpolateNormalized_r(ctx.as_raw(), self.as_raw(), d))?;
+    /// Returns the smallest circle containing `self`.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("LINESTRING (0 0, 10 0)")?;
+    /// let circle = geom.minimum_bounding_circle()?;
+    ///
+    /// assert_eq!(circle.radius, 5.);
+    /// assert_eq!(circle.center.to_wkt()?, "POINT (5 0)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_8_0")]
+    fn minimum_bounding_circle(&self) -> GResult<Circle> {
+        let mut radius = 0.;
+        let mut center: *mut GEOSGeometry = ::std::ptr::null_mut();
+
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSMinimumBoundingCircle_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                &mut radius,
+                &mut center,
+            ))?;
+            let geometry = Geometry::new_from_raw(ptr);
+            let center = NonNull::new(center)
+                .ok_or_else(|| ctx.take_error("GEOSMinimumBoundingCircle_r"))?;
+            Ok(Circle {
+                geometry,
+                center: Geometry::new_from_raw(center),
+                radius,
+            })
+        })
+    }
+
+    /// Returns a [delaunay triangulation](https://en.wikipedia.org/wiki/Delaunay_triangulation)
+    /// around the vertices of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((175 150, 20 40, 50 60, 125 100, 175 150))")?;
+    /// let geom2 = Geometry::new_from_wkt("POINT(110 170)")?;
+    /// let geom2 = geom2.buffer(20., 8)?;
+    ///
+    /// let geom = geom1.union(&geom2)?;
+    ///
+    /// let final_geom = geom.delaunay_triangulation(0.001, false)?;
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn delaunay_triangulation(&self, tolerance: f64, only_edges: bool) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSDelaunayTriangulation_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                tolerance,
+                only_edges.into(),
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Returns a constrained [delaunay triangulation](https://en.wikipedia.org/wiki/Constrained_Delaunay_triangulation)
+    /// of the polygons of `self`, as a `GeometryCollection` of triangular `Polygon`s. The
+    /// triangles cover exactly the input polygons and their edges contain the polygon edges.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON ((0 0, 10 0, 10 10, 5 5, 0 10, 0 0))")?;
+    /// let triangles = geom.constrained_delaunay_triangulation()?;
+    ///
+    /// assert_eq!(triangles.get_num_geometries()?, 3);
+    /// assert_eq!(triangles.area()?, geom.area()?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_10_0")]
+    fn constrained_delaunay_triangulation(&self) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSConstrainedDelaunayTriangulation_r(
+                ctx.as_raw(),
+                self.as_raw()
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    fn interpolate(&self, d: f64) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSInterpolate_r(ctx.as_raw(), self.as_raw(), d))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    fn interpolate_normalized(&self, d: f64) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSInterpolateNormalized_r(ctx.as_raw(), self.as_raw(), d))?;
             Ok(Geometry::new_from_raw(ptr))
         })
     }
@@ -2222,21 +2774,24 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
-    /// Returns, in the tuple elements order:
+    /// Polygonizes the linework of `self`, and also returns the edges which couldn't be used to
+    /// build polygons: see [`PolygonizeResult`] for the details.
     ///
-    /// 1. The polygonized geometry.
-    /// 2. The cuts geometries collection.
-    /// 3. The dangles geometries collection.
-    /// 4. The invalid geometries collection.
-    #[allow(clippy::type_complexity)]
-    fn polygonize_full(
-        &self,
-    ) -> GResult<(
-        Geometry,
-        Option<Geometry>,
-        Option<Geometry>,
-        Option<Geometry>,
-    )> {
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let lines = Geometry::new_from_wkt("MULTILINESTRING ((0 0, 1 0, 1 1, 0 1, 0 0), (1 1, 2 2))")?;
+    /// let result = lines.polygonize_full()?;
+    ///
+    /// assert_eq!(result.polygons.get_num_geometries()?, 1);
+    /// assert_eq!(result.polygons.area()?, 1.);
+    /// let dangles = result.dangles.expect("no dangles");
+    /// assert_eq!(dangles.get_num_geometries()?, 1);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    fn polygonize_full(&self) -> GResult<PolygonizeResult> {
         let mut cuts: *mut GEOSGeometry = ::std::ptr::null_mut();
         let mut dangles: *mut GEOSGeometry = ::std::ptr::null_mut();
         let mut invalids: *mut GEOSGeometry = ::std::ptr::null_mut();
@@ -2251,10 +2806,12 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
             ))?;
             let geom = Geometry::new_from_raw(ptr);
 
-            let cuts = NonNull::new(cuts).map(Geometry::new_from_raw);
-            let dangles = NonNull::new(dangles).map(Geometry::new_from_raw);
-            let invalids = NonNull::new(invalids).map(Geometry::new_from_raw);
-            Ok((geom, cuts, dangles, invalids))
+            Ok(PolygonizeResult {
+                polygons: geom,
+                cuts: NonNull::new(cuts).map(Geometry::new_from_raw),
+                dangles: NonNull::new(dangles).map(Geometry::new_from_raw),
+                invalid_rings: NonNull::new(invalids).map(Geometry::new_from_raw),
+            })
         })
     }
 
@@ -2334,6 +2891,32 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         GeoJSONWriter::new()?.write_formatted(self, indent)
     }
 
+    /// Converts a [`Geometry`] to the `GeoJSON` format, following the
+    /// [RFC 7946](https://www.rfc-editor.org/rfc/rfc7946#section-3.1.6) winding order: exterior
+    /// rings are counter-clockwise and interior rings are clockwise.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let clockwise = Geometry::new_from_wkt("POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))")?;
+    /// let counter_clockwise = Geometry::new_from_wkt("POLYGON ((0 0, 1 0, 1 1, 0 1, 0 0))")?;
+    /// assert_eq!(
+    ///     clockwise.to_geojson_rfc7946()?,
+    ///     counter_clockwise.to_geojson()?,
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn to_geojson_rfc7946(&self) -> GResult<String> {
+        let mut writer = GeoJSONWriter::new()?;
+        writer.set_rfc7946(true);
+        writer.write(self)
+    }
+
     /// Creates a new [`PreparedGeometry`] from the current `Geometry`.
     ///
     /// # Example
@@ -2450,7 +3033,7 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
             } else if let Some(err) = trampoline.err {
                 Err(err)
             } else {
-                Err(Error::GeosError(("GEOSGeom_transformXY_r", ctx.get_last_error())).into())
+                Err(ctx.take_error("GEOSGeom_transformXY_r").into())
             }
         })
     }
@@ -2474,7 +3057,7 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
             } else if let Some(err) = trampoline.err {
                 Err(err)
             } else {
-                Err(Error::GeosError(("GEOSGeom_transformXY_r", ctx.get_last_error())).into())
+                Err(ctx.take_error("GEOSGeom_transformXY_r").into())
             }
         })
     }
@@ -2526,6 +3109,123 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
         })
     }
 
+    /// Returns the concave hull of the vertices of `self`, whose edges are not longer than
+    /// `length`. A `length` of `0` gives the tightest hull.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom =
+    ///     Geometry::new_from_wkt("MULTIPOINT ((0 0), (10 0), (10 1), (1 1), (1 10), (0 10))")?;
+    ///
+    /// // No edge is longer than 100: this is the convex hull.
+    /// let convex_hull = geom.concave_hull_by_length(100., false)?;
+    /// assert_eq!(convex_hull.area()?, 59.5);
+    ///
+    /// let concave_hull = geom.concave_hull_by_length(2., false)?;
+    /// assert!(concave_hull.area()? < 59.5);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    fn concave_hull_by_length(&self, length: f64, allow_holes: bool) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSConcaveHullByLength_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                length,
+                allow_holes.into()
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Returns a concave hull of the polygons of `self`, filling the space between them without
+    /// overlapping them.
+    ///
+    /// `length_ratio` is the maximum length of the hull edges, as a fraction of the difference
+    /// between the longest and shortest edges of the triangulation of the polygons. If `is_tight`
+    /// is `true`, the hull follows the outer boundaries of the input polygons.
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "MULTIPOLYGON (((0 0, 4 0, 4 4, 0 4, 0 0)), ((6 0, 10 0, 10 4, 6 4, 6 0)))",
+    /// )?;
+    /// let hull = geom.concave_hull_of_polygons(1., false, false)?;
+    ///
+    /// assert_eq!(hull.get_num_geometries()?, 1);
+    /// assert!(hull.covers(&geom)?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_11_0")]
+    fn concave_hull_of_polygons(
+        &self,
+        length_ratio: f64,
+        is_tight: bool,
+        allow_holes: bool,
+    ) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSConcaveHullOfPolygons_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                length_ratio,
+                is_tight.into(),
+                allow_holes.into()
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Simplifies the polygons of `self` into a hull which contains them if `is_outer` is `true`,
+    /// or which is contained by them otherwise.
+    ///
+    /// `mode` tells how `parameter` is interpreted: as the fraction of vertices to keep, or as
+    /// the ratio between the areas of the input and of the hull. `1` returns the input unchanged.
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, PolygonHullMode};
+    ///
+    /// let geom = Geometry::new_from_wkt("POLYGON ((0 0, 10 0, 10 10, 5 9, 0 10, 0 0))")?;
+    ///
+    /// let outer = geom.polygon_hull_simplify(true, PolygonHullMode::VertexRatio, 0.)?;
+    /// assert!(outer.contains(&geom)?);
+    ///
+    /// let inner = geom.polygon_hull_simplify(false, PolygonHullMode::AreaRatio, 0.9)?;
+    /// assert!(geom.contains(&inner)?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_11_0")]
+    fn polygon_hull_simplify(
+        &self,
+        is_outer: bool,
+        mode: PolygonHullMode,
+        parameter: f64,
+    ) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSPolygonHullSimplifyMode_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                is_outer.into(),
+                mode.into(),
+                parameter
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
     #[cfg(feature = "v3_11_0")]
     fn get_extent(&self) -> GResult<Vec<f64>> {
         with_context(|ctx| unsafe {
@@ -2557,6 +3257,175 @@ pub trait Geom: AsRaw<RawType = GEOSGeometry> + Sized + Send + Sync {
             Ok(Geometry::new_from_raw(ptr))
         })
     }
+
+    /// Clusters the components of `self` using the
+    /// [DBSCAN](https://en.wikipedia.org/wiki/DBSCAN) algorithm.
+    ///
+    /// A component is a core point if at least `min_points` components are within `eps` of it.
+    /// Components which are neither core points nor within `eps` of a core point are noise and
+    /// don't belong to any cluster.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT ((0 0), (1 0), (0 1), (50 50))")?;
+    /// let clusters = geom.cluster_dbscan(1.5, 2)?;
+    ///
+    /// assert_eq!(clusters.get_num_clusters(), 1);
+    /// assert_eq!(clusters.get_clusters_for_inputs()?[3], None);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn cluster_dbscan(&self, eps: f64, min_points: u32) -> GResult<ClusterInfo> {
+        let num_inputs = self.get_num_geometries()?;
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSClusterDBSCAN_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                eps,
+                min_points
+            ))?;
+            Ok(ClusterInfo::new_from_raw(ptr, num_inputs))
+        })
+    }
+
+    /// Clusters the components of `self` which are within `distance` of each other.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTILINESTRING ((0 0, 1 0), (1.5 0, 3 0), (10 0, 11 0))")?;
+    /// let clusters = geom.cluster_geometry_distance(1.)?;
+    ///
+    /// assert_eq!(clusters.get_num_clusters(), 2);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn cluster_geometry_distance(&self, distance: f64) -> GResult<ClusterInfo> {
+        let num_inputs = self.get_num_geometries()?;
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSClusterGeometryDistance_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                distance
+            ))?;
+            Ok(ClusterInfo::new_from_raw(ptr, num_inputs))
+        })
+    }
+
+    /// Clusters the components of `self` which intersect each other.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTILINESTRING ((0 0, 2 2), (0 2, 2 0), (10 0, 11 0))")?;
+    /// let clusters = geom.cluster_geometry_intersects()?;
+    ///
+    /// assert_eq!(clusters.get_num_clusters(), 2);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn cluster_geometry_intersects(&self) -> GResult<ClusterInfo> {
+        let num_inputs = self.get_num_geometries()?;
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSClusterGeometryIntersects_r(ctx.as_raw(), self.as_raw()))?;
+            Ok(ClusterInfo::new_from_raw(ptr, num_inputs))
+        })
+    }
+
+    /// Clusters the components of `self` whose envelopes are within `distance` of each other.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTIPOINT ((0 0), (0 1), (10 10))")?;
+    /// let clusters = geom.cluster_envelope_distance(2.)?;
+    ///
+    /// assert_eq!(clusters.get_num_clusters(), 2);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn cluster_envelope_distance(&self, distance: f64) -> GResult<ClusterInfo> {
+        let num_inputs = self.get_num_geometries()?;
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSClusterEnvelopeDistance_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                distance
+            ))?;
+            Ok(ClusterInfo::new_from_raw(ptr, num_inputs))
+        })
+    }
+
+    /// Clusters the components of `self` whose envelopes intersect each other.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt("MULTILINESTRING ((0 0, 2 2), (1 1, 3 0), (10 0, 11 1))")?;
+    /// let clusters = geom.cluster_envelope_intersects()?;
+    ///
+    /// assert_eq!(clusters.get_num_clusters(), 2);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn cluster_envelope_intersects(&self) -> GResult<ClusterInfo> {
+        let num_inputs = self.get_num_geometries()?;
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSClusterEnvelopeIntersects_r(ctx.as_raw(), self.as_raw()))?;
+            Ok(ClusterInfo::new_from_raw(ptr, num_inputs))
+        })
+    }
+
+    /// Returns the fraction of the area of each cell of a grid of `nx` columns and `ny` rows
+    /// covering the envelope of `extent` which is covered by `self`, which must be polygonal.
+    ///
+    /// The fractions are in row-major order, starting from the upper-left cell: see [`GridSpec`]
+    /// to describe the grid and locate its cells.
+    ///
+    /// Available using the `v3_14_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let extent = Geometry::create_rectangle(0., 0., 4., 4.)?;
+    /// let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 3 0, 3 2, 0 2, 0 0))")?;
+    ///
+    /// let fractions = polygon.grid_intersection_fractions(&extent, 2, 2)?;
+    /// assert_eq!(fractions, vec![0., 0., 1., 0.5]);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_14_0")]
+    fn grid_intersection_fractions<G: Geom>(
+        &self,
+        extent: &G,
+        nx: u32,
+        ny: u32,
+    ) -> GResult<Vec<f32>> {
+        GridSpec::from_extent(extent, nx, ny)?.intersection_fractions(self)
+    }
 }
 
 /// Trampoline function helper function to get the trampoline function from the closure.
@@ -2652,11 +3521,16 @@ impl ConstGeometry<'_> {
 
 impl Geometry {
     pub(crate) const fn new_from_raw(ptr: NonNull<GEOSGeometry>) -> Self {
-        Self { ptr }
+        Self {
+            ptr,
+            has_user_data: false,
+        }
     }
 
     /// Creates a `Geometry` from the WKT format.
     ///
+    /// To read many geometries, or to repair unclosed rings, use a [`WKTReader`] instead.
+    ///
     /// # Example
     ///
     /// ```
@@ -2666,17 +3540,7 @@ impl Geometry {
     /// # Ok::<(), geos::Error>(())
     /// ```
     pub fn new_from_wkt(wkt: &str) -> GResult<Self> {
-        with_context(|ctx| match CString::new(wkt) {
-            Ok(c_str) => unsafe {
-                let reader = nullcheck!(GEOSWKTReader_create_r(ctx.as_raw()))?.as_ptr();
-                let ptr = nullcheck!(GEOSWKTReader_read_r(ctx.as_raw(), reader, c_str.as_ptr()))?;
-                GEOSWKTReader_destroy_r(ctx.as_raw(), reader);
-                Ok(Self::new_from_raw(ptr))
-            },
-            Err(e) => Err(Error::GenericError(format!(
-                "Conversion to CString failed with {e}",
-            ))),
-        })
+        WKTReader::new()?.read(wkt)
     }
 
     /// Create a new [`Geometry`] from the HEX format.
@@ -2842,6 +3706,45 @@ impl Geometry {
         })
     }
 
+    /// Polygonizes a set of geometries like [`polygonize`](Geometry::polygonize), but only keeps
+    /// polygons which form a valid polygonal geometry: polygons nested inside other ones are
+    /// dropped.
+    ///
+    /// Available using the `v3_8_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let outer = Geometry::new_from_wkt("LINESTRING (0 0, 4 0, 4 4, 0 4, 0 0)")?;
+    /// let inner = Geometry::new_from_wkt("LINESTRING (1 1, 2 1, 2 2, 1 2, 1 1)")?;
+    /// let lines = [outer, inner];
+    ///
+    /// let polygonized = Geometry::polygonize(&lines)?;
+    /// assert_eq!(polygonized.get_num_geometries()?, 2);
+    ///
+    /// let polygonized = Geometry::polygonize_valid(&lines)?;
+    /// assert!(polygonized.is_valid()?);
+    /// assert_eq!(polygonized.get_num_geometries()?, 1);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_8_0")]
+    pub fn polygonize_valid<T: Borrow<Self>>(geometries: &[T]) -> GResult<Self> {
+        with_context(|ctx| unsafe {
+            let geoms = geometries
+                .iter()
+                .map(|g| g.borrow().as_raw().cast())
+                .collect::<Vec<_>>();
+            let ptr = nullcheck!(GEOSPolygonize_valid_r(
+                ctx.as_raw(),
+                geoms.as_ptr(),
+                geoms.len() as _
+            ))?;
+            Ok(Self::new_from_raw(ptr))
+        })
+    }
+
     pub fn polygonizer_get_cut_edges<T: Borrow<Self>>(&self, geometries: &[T]) -> GResult<Self> {
         with_context(|ctx| unsafe {
             let geoms = geometries
@@ -2954,6 +3857,62 @@ impl Geometry {
         with_context(|ctx| unsafe { GEOSSetSRID_r(ctx.as_raw(), self.as_raw_mut(), srid) });
     }
 
+    /// Attaches `data` to `self`, replacing any previously attached data. It can be read back
+    /// with [`Geom::user_data`], including from the components of a collection built from
+    /// `self`, and is freed when `self` is dropped.
+    ///
+    /// Geometries returned by GEOS operations, including [`Geom::clone`], don't carry the data of
+    /// their inputs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let mut line1 = Geometry::new_from_wkt("LINESTRING (0 0, 1 1)")?;
+    /// line1.set_user_data(String::from("first"));
+    /// let mut line2 = Geometry::new_from_wkt("LINESTRING (2 2, 3 3)")?;
+    /// line2.set_user_data(String::from("second"));
+    ///
+    /// let lines = Geometry::create_multiline_string(vec![line1, line2])?;
+    /// let second = lines.get_geometry_n(1)?;
+    /// assert_eq!(second.user_data::<String>().map(String::as_str), Some("second"));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn set_user_data<T: Any + Send + Sync>(&mut self, data: T) {
+        with_context(|ctx| unsafe {
+            drop(detach_user_data(ctx, self.as_raw_mut()));
+            attach_user_data(ctx, self.as_raw_mut(), Box::new(Box::new(data)));
+        });
+        self.has_user_data = true;
+    }
+
+    /// Detaches and returns the data attached to `self` with [`Geometry::set_user_data`]. Returns
+    /// `None`, and leaves the data attached, if there is none or if it isn't of type `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let mut point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+    /// point_geom.set_user_data(42u64);
+    ///
+    /// assert_eq!(point_geom.take_user_data::<i32>(), None);
+    /// assert_eq!(point_geom.take_user_data::<u64>(), Some(42));
+    /// assert_eq!(point_geom.user_data::<u64>(), None);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn take_user_data<T: Any>(&mut self) -> Option<T> {
+        with_context(|ctx| unsafe {
+            if !get_user_data(ctx, self.as_raw()).as_ref()?.is::<T>() {
+                return None;
+            }
+            let data = detach_user_data(ctx, self.as_raw_mut())?;
+            data.downcast().ok().map(|data| *data)
+        })
+    }
+
     /// Normalizes `self` in its normalized/canonical form. May reorder vertices in polygon rings,
     /// rings in a polygon, elements in a multi-geometry complex.
     ///
@@ -2981,6 +3940,60 @@ impl Geometry {
         })
     }
 
+    /// Splits a collection into the geometries it contains, without copying them.
+    ///
+    /// The data attached to the parts with [`Geometry::set_user_data`] is kept.
+    ///
+    /// Available using the `v3_12_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom = Geometry::new_from_wkt(
+    ///     "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((5 5, 6 5, 6 6, 5 5)))",
+    /// )?;
+    /// let parts = geom.into_parts()?;
+    ///
+    /// assert_eq!(parts.len(), 2);
+    /// assert_eq!(parts[1].to_wkt()?, "POLYGON ((5 5, 6 5, 6 6, 5 5))");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_12_0")]
+    pub fn into_parts(mut self) -> GResult<Vec<Self>> {
+        let geom_type = self.geometry_type()?;
+        if !geom_type.is_collection() {
+            return Err(Error::ImpossibleOperation(format!(
+                "into_parts can only split collections, not {geom_type:?}"
+            )));
+        }
+        // GEOS returns a null pointer for empty collections.
+        if self.get_num_geometries()? == 0 {
+            return Ok(Vec::new());
+        }
+
+        with_context(|ctx| unsafe {
+            let mut nb_parts = 0;
+            let ptr: NonNull<*mut GEOSGeometry> = nullcheck!(GEOSGeom_releaseCollection_r(
+                ctx.as_raw(),
+                self.as_raw_mut(),
+                &mut nb_parts
+            ))?;
+            // The parts are now owned by us, `self` is left as an empty collection.
+            let parts = std::slice::from_raw_parts(ptr.as_ptr(), nb_parts as _)
+                .iter()
+                .filter_map(|part| NonNull::new(*part).map(Self::new_from_raw))
+                .map(|mut part: Self| {
+                    part.has_user_data = self.has_user_data;
+                    part
+                })
+                .collect();
+            GEOSFree_r(ctx.as_raw(), ptr.as_ptr().cast());
+            Ok(parts)
+        })
+    }
+
     /// Creates an empty polygon geometry.
     ///
     /// # Example
@@ -3154,6 +4167,7 @@ impl Geometry {
     /// # Ok::<(), geos::Error>(())
     /// ```
     pub fn create_polygon(mut exterior: Self, mut interiors: Vec<Self>) -> GResult<Self> {
+        let has_user_data = exterior.has_user_data || interiors.iter().any(|g| g.has_user_data);
         let nb_interiors = interiors.len();
         let polygon = with_context(|ctx| unsafe {
             let mut geoms: Vec<*mut GEOSGeometry> =
@@ -3174,7 +4188,10 @@ impl Geometry {
             std::mem::forget(interior);
         }
 
-        polygon
+        polygon.map(|mut polygon| {
+            polygon.has_user_data = has_user_data;
+            polygon
+        })
     }
 
     /// Create a geometry collection.
@@ -3339,6 +4356,136 @@ impl Geometry {
         create_multi_geom(surfaces, GeometryTypes::MultiSurface)
     }
 
+    /// Creates a compound curve formed by the given sections, each one starting where the
+    /// previous one ends.
+    ///
+    /// ### Note
+    ///
+    /// `curves` must only contain `LineString` and `CircularString` geometries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let arc = Geometry::new_from_wkt("CIRCULARSTRING (0 0, 1 1, 2 0)")?;
+    /// let line = Geometry::new_from_wkt("LINESTRING (2 0, 4 0)")?;
+    ///
+    /// let geom = Geometry::create_compound_curve(vec![arc, line])?;
+    ///
+    /// assert_eq!(
+    ///     geom.to_wkt()?,
+    ///     "COMPOUNDCURVE (CIRCULARSTRING (0 0, 1 1, 2 0), (2 0, 4 0))"
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_13_0")]
+    pub fn create_compound_curve(mut curves: Vec<Self>) -> GResult<Self> {
+        if !curves.iter().all(|g| {
+            matches!(
+                g.geometry_type(),
+                Ok(GeometryTypes::LineString | GeometryTypes::CircularString)
+            )
+        }) {
+            return Err(Error::ImpossibleOperation(
+                "all the provided geometry have to be of type LineString or CircularString"
+                    .to_owned(),
+            ));
+        }
+
+        let has_user_data = curves.iter().any(|g| g.has_user_data);
+        let nb_curves = curves.len();
+        let compound_curve = with_context(|ctx| unsafe {
+            let mut geoms: Vec<*mut GEOSGeometry> =
+                curves.iter_mut().map(AsRawMut::as_raw_mut).collect();
+            let ptr = nullcheck!(GEOSGeom_createCompoundCurve_r(
+                ctx.as_raw(),
+                geoms.as_mut_ptr().cast(),
+                nb_curves as _,
+            ))?;
+            Ok(Self::new_from_raw(ptr))
+        });
+
+        // We transfered the ownership of the ptr to the new Geometry,
+        // so the old ones need to forget their c ptr to avoid double free.
+        for curve in curves {
+            std::mem::forget(curve);
+        }
+
+        compound_curve.map(|mut compound_curve| {
+            compound_curve.has_user_data = has_user_data;
+            compound_curve
+        })
+    }
+
+    /// Creates a curve polygon formed by the given shell and array of holes.
+    ///
+    /// ### Note
+    ///
+    /// `exterior` and `interiors` must be closed `LinearRing`, `CircularString` or
+    /// `CompoundCurve` geometries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let shell = Geometry::new_from_wkt("CIRCULARSTRING (0 0, 4 0, 0 0)")?;
+    /// let hole = Geometry::new_from_wkt("LINEARRING (1 -1, 3 -1, 3 1, 1 1, 1 -1)")?;
+    ///
+    /// let geom = Geometry::create_curve_polygon(shell, vec![hole])?;
+    ///
+    /// assert_eq!(
+    ///     geom.to_wkt()?,
+    ///     "CURVEPOLYGON (CIRCULARSTRING (0 0, 4 0, 0 0), (1 -1, 3 -1, 3 1, 1 1, 1 -1))"
+    /// );
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_13_0")]
+    pub fn create_curve_polygon(mut exterior: Self, mut interiors: Vec<Self>) -> GResult<Self> {
+        let is_ring = |g: &Self| {
+            matches!(
+                g.geometry_type(),
+                Ok(GeometryTypes::LinearRing
+                    | GeometryTypes::CircularString
+                    | GeometryTypes::CompoundCurve)
+            )
+        };
+        if !is_ring(&exterior) || !interiors.iter().all(is_ring) {
+            return Err(Error::ImpossibleOperation(
+                "all the provided rings have to be of type LinearRing, CircularString or \
+                 CompoundCurve"
+                    .to_owned(),
+            ));
+        }
+
+        let has_user_data = exterior.has_user_data || interiors.iter().any(|g| g.has_user_data);
+        let nb_interiors = interiors.len();
+        let polygon = with_context(|ctx| unsafe {
+            let mut geoms: Vec<*mut GEOSGeometry> =
+                interiors.iter_mut().map(AsRawMut::as_raw_mut).collect();
+            let ptr = nullcheck!(GEOSGeom_createCurvePolygon_r(
+                ctx.as_raw(),
+                exterior.as_raw_mut(),
+                geoms.as_mut_ptr().cast(),
+                nb_interiors as _,
+            ))?;
+            Ok(Self::new_from_raw(ptr))
+        });
+
+        // We transfered the ownership of the ptr to the new Geometry,
+        // so the old ones need to forget their c ptr to avoid double free.
+        std::mem::forget(exterior);
+        for interior in interiors {
+            std::mem::forget(interior);
+        }
+
+        polygon.map(|mut polygon| {
+            polygon.has_user_data = has_user_data;
+            polygon
+        })
+    }
+
     /// Creates a point geometry.
     ///
     /// # Example
@@ -3488,9 +4635,145 @@ impl Clone for Geometry {
     }
 }
 
+impl fmt::Debug for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_geometry(self, "Geometry", f)
+    }
+}
+
+impl fmt::Debug for ConstGeometry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_geometry(self, "ConstGeometry", f)
+    }
+}
+
+/// Formats a geometry as its WKT, or as its pointer if it can't be converted.
+fn debug_geometry<G: Geom>(geom: &G, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match geom.to_wkt() {
+        Ok(wkt) => f.debug_tuple(name).field(&wkt).finish(),
+        Err(_) => f.debug_tuple(name).field(&geom.as_raw()).finish(),
+    }
+}
+
 impl Drop for Geometry {
     fn drop(&mut self) {
-        with_context(|ctx| unsafe { GEOSGeom_destroy_r(ctx.as_raw(), self.as_raw_mut()) });
+        with_context(|ctx| unsafe {
+            if self.has_user_data {
+                free_user_data(ctx, self.as_raw_mut());
+            }
+            GEOSGeom_destroy_r(ctx.as_raw(), self.as_raw_mut());
+        });
+    }
+}
+
+/// The data attached with [`Geometry::set_user_data`]. It is boxed twice since GEOS only stores
+/// a thin pointer.
+type UserData = Box<dyn Any + Send + Sync>;
+
+/// The addresses of the data attached with [`Geometry::set_user_data`]. GEOS may set the user
+/// data of the geometries it builds for its own purposes, so a pointer which isn't listed here
+/// must never be read or freed.
+static USER_DATA: Mutex<Option<HashSet<usize>>> = Mutex::new(None);
+/// The number of entries of [`USER_DATA`], to avoid locking it when no data is attached at all.
+static USER_DATA_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+fn user_data_registry() -> MutexGuard<'static, Option<HashSet<usize>>> {
+    USER_DATA.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns the data attached to `ptr` with [`Geometry::set_user_data`], or a null pointer.
+unsafe fn get_user_data(ctx: &ContextHandle, ptr: *const GEOSGeometry) -> *const UserData {
+    if USER_DATA_COUNT.load(Ordering::Relaxed) == 0 {
+        return std::ptr::null();
+    }
+    let data = GEOSGeom_getUserData_r(ctx.as_raw(), ptr);
+    let is_ours = !data.is_null()
+        && user_data_registry()
+            .as_ref()
+            .map_or(false, |registry| registry.contains(&(data as usize)));
+    if is_ours {
+        data.cast()
+    } else {
+        std::ptr::null()
+    }
+}
+
+unsafe fn attach_user_data(ctx: &ContextHandle, ptr: *mut GEOSGeometry, data: Box<UserData>) {
+    let data = Box::into_raw(data);
+    user_data_registry()
+        .get_or_insert_with(HashSet::new)
+        .insert(data as usize);
+    USER_DATA_COUNT.fetch_add(1, Ordering::Relaxed);
+    GEOSGeom_setUserData_r(ctx.as_raw(), ptr, data.cast());
+}
+
+/// Detaches the data attached to `ptr` with [`Geometry::set_user_data`] and gives back its
+/// ownership.
+unsafe fn detach_user_data(ctx: &ContextHandle, ptr: *mut GEOSGeometry) -> Option<Box<UserData>> {
+    remove_user_data(ctx, ptr, user_data_registry().as_mut()?)
+}
+
+unsafe fn remove_user_data(
+    ctx: &ContextHandle,
+    ptr: *mut GEOSGeometry,
+    registry: &mut HashSet<usize>,
+) -> Option<Box<UserData>> {
+    let data = GEOSGeom_getUserData_r(ctx.as_raw(), ptr);
+    if data.is_null() || !registry.remove(&(data as usize)) {
+        return None;
+    }
+    USER_DATA_COUNT.fetch_sub(1, Ordering::Relaxed);
+    GEOSGeom_setUserData_r(ctx.as_raw(), ptr, std::ptr::null_mut());
+    Some(Box::from_raw(data.cast()))
+}
+
+/// Frees the data attached to `ptr` and to all its components, sections and rings, since they
+/// are destroyed along with it.
+unsafe fn free_user_data(ctx: &ContextHandle, ptr: *mut GEOSGeometry) {
+    let mut detached = Vec::new();
+    if let Some(registry) = user_data_registry().as_mut() {
+        collect_user_data(ctx, ptr, registry, &mut detached);
+    }
+    // The data is only dropped once the registry is unlocked, since its destructor may attach
+    // data to another geometry.
+    drop(detached);
+}
+
+unsafe fn collect_user_data(
+    ctx: &ContextHandle,
+    ptr: *mut GEOSGeometry,
+    registry: &mut HashSet<usize>,
+    detached: &mut Vec<UserData>,
+) {
+    detached.extend(remove_user_data(ctx, ptr, registry).map(|data| *data));
+
+    let Ok(geom_type) = GeometryTypes::try_from(GEOSGeomTypeId_r(ctx.as_raw(), ptr)) else {
+        return;
+    };
+    #[cfg(feature = "v3_13_0")]
+    let has_parts = geom_type.is_collection() || geom_type == GeometryTypes::CompoundCurve;
+    #[cfg(not(feature = "v3_13_0"))]
+    let has_parts = geom_type.is_collection();
+    if has_parts {
+        for n in 0..GEOSGetNumGeometries_r(ctx.as_raw(), ptr).max(0) {
+            let part = GEOSGetGeometryN_r(ctx.as_raw(), ptr, n);
+            // GEOS returns the geometry itself when it has no parts.
+            if !part.is_null() && part != ptr.cast_const() {
+                collect_user_data(ctx, part.cast_mut(), registry, detached);
+            }
+        }
+    } else if geom_type.is_surface() {
+        // Curve polygons can have compound curves as rings.
+        let exterior = GEOSGetExteriorRing_r(ctx.as_raw(), ptr);
+        if !exterior.is_null() {
+            collect_user_data(ctx, exterior.cast_mut(), registry, detached);
+        }
+        for n in 0..GEOSGetNumInteriorRings_r(ctx.as_raw(), ptr).max(0) {
+            let interior = GEOSGetInteriorRingN_r(ctx.as_raw(), ptr, n);
+            if !interior.is_null() {
+                collect_user_data(ctx, interior.cast_mut(), registry, detached);
+            }
+        }
     }
 }
 
@@ -0,0 +1,110 @@
+use crate::context_handle::with_context;
+use crate::error::{Error, GResult};
+use crate::functions::errcheck;
+use crate::Geom;
+use geos_sys::*;
+
+/// Describes a regular grid of `nx` columns and `ny` rows covering a rectangular extent, such as
+/// the cells of a raster.
+///
+/// Cells are numbered in row-major order, starting from the upper-left corner: row `0` is the
+/// one touching `y_max` and column `0` is the one touching `x_min`.
+///
+/// Available using the `v3_14_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geometry, GridSpec};
+///
+/// let grid = GridSpec::new(0., 0., 4., 4., 2, 2);
+/// let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 3 0, 3 2, 0 2, 0 0))")?;
+///
+/// // Upper-left, upper-right, lower-left and lower-right cells.
+/// assert_eq!(grid.intersection_fractions(&polygon)?, vec![0., 0., 1., 0.5]);
+/// assert_eq!(grid.cell_bounds(1, 0), (0., 0., 2., 2.));
+/// # Ok::<(), geos::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridSpec {
+    /// The smallest X of the extent.
+    pub x_min: f64,
+    /// The smallest Y of the extent.
+    pub y_min: f64,
+    /// The largest X of the extent.
+    pub x_max: f64,
+    /// The largest Y of the extent.
+    pub y_max: f64,
+    /// The number of columns.
+    pub nx: u32,
+    /// The number of rows.
+    pub ny: u32,
+}
+
+impl GridSpec {
+    /// Creates a grid of `nx` columns and `ny` rows covering the given extent.
+    pub const fn new(x_min: f64, y_min: f64, x_max: f64, y_max: f64, nx: u32, ny: u32) -> Self {
+        Self {
+            x_min,
+            y_min,
+            x_max,
+            y_max,
+            nx,
+            ny,
+        }
+    }
+
+    /// Creates a grid of `nx` columns and `ny` rows covering the envelope of `extent`.
+    pub fn from_extent<G: Geom>(extent: &G, nx: u32, ny: u32) -> GResult<Self> {
+        Ok(Self::new(
+            extent.get_x_min()?,
+            extent.get_y_min()?,
+            extent.get_x_max()?,
+            extent.get_y_max()?,
+            nx,
+            ny,
+        ))
+    }
+
+    /// Returns the width of a cell.
+    pub fn cell_width(&self) -> f64 {
+        (self.x_max - self.x_min) / f64::from(self.nx)
+    }
+
+    /// Returns the height of a cell.
+    pub fn cell_height(&self) -> f64 {
+        (self.y_max - self.y_min) / f64::from(self.ny)
+    }
+
+    /// Returns the bounds of the cell at the given `row` and `col`, as
+    /// `(x_min, y_min, x_max, y_max)`.
+    pub fn cell_bounds(&self, row: u32, col: u32) -> (f64, f64, f64, f64) {
+        let (width, height) = (self.cell_width(), self.cell_height());
+        let x_min = self.x_min + f64::from(col) * width;
+        let y_max = self.y_max - f64::from(row) * height;
+        (x_min, y_max - height, x_min + width, y_max)
+    }
+
+    /// Returns the fraction of the area of each cell covered by the polygonal geometry `geom`,
+    /// in row-major order starting from the upper-left cell.
+    pub fn intersection_fractions<G: Geom>(&self, geom: &G) -> GResult<Vec<f32>> {
+        let size = (self.nx as usize)
+            .checked_mul(self.ny as usize)
+            .ok_or_else(|| Error::GenericError("Grid is too large".into()))?;
+        let mut fractions = vec![0.; size];
+        with_context(|ctx| unsafe {
+            errcheck!(GEOSGridIntersectionFractions_r(
+                ctx.as_raw(),
+                geom.as_raw(),
+                self.x_min,
+                self.y_min,
+                self.x_max,
+                self.y_max,
+                self.nx,
+                self.ny,
+                fractions.as_mut_ptr(),
+            ))?;
+            Ok(fractions)
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use crate::context_handle::{with_context, InterruptCallback};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Runs `f`, interrupting the GEOS operations it calls on the current thread as soon as
+/// `should_interrupt` returns `true`: they then return [`Error::Interrupted`](crate::Error::Interrupted).
+///
+/// Calls can be nested: the operations are also interrupted when the callback of an enclosing
+/// scope asks for it, and the previous interrupt callback of the thread is restored when `f`
+/// returns. `should_interrupt` must not panic.
+///
+/// Available using the `v3_14_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{with_interrupt, Error, Geom, Geometry};
+///
+/// let geom = Geometry::new_from_wkt("LINESTRING (0 0, 10 10, 0 10, 10 0)")?;
+///
+/// let result = with_interrupt(|| true, || geom.buffer(1., 8));
+/// assert!(matches!(result, Err(Error::Interrupted)));
+///
+/// let result = with_interrupt(|| false, || geom.buffer(1., 8));
+/// assert!(result.is_ok());
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub fn with_interrupt<R>(
+    should_interrupt: impl Fn() -> bool + Send + Sync + 'static,
+    f: impl FnOnce() -> R,
+) -> R {
+    // Restores the previous callback even if `f` panics.
+    struct Restore(Option<InterruptCallback>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            with_context(|ctx| ctx.set_interrupt_callback(previous));
+        }
+    }
+
+    let _restore = Restore(with_context(|ctx| {
+        let previous = ctx.interrupt_callback();
+        let callback: InterruptCallback = match previous.clone() {
+            Some(previous) => Arc::new(move || should_interrupt() || previous()),
+            None => Arc::new(should_interrupt),
+        };
+        // Setting the callback also clears an interruption left over by a previous scope.
+        ctx.set_interrupt_callback(Some(callback));
+        previous
+    }));
+    f()
+}
+
+/// Runs `f`, interrupting the GEOS operations it calls on the current thread once `deadline` is
+/// reached. See [`with_interrupt`].
+///
+/// Available using the `v3_14_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{with_deadline, Geom, Geometry};
+/// use std::time::{Duration, Instant};
+///
+/// let geom = Geometry::new_from_wkt("POINT (0 0)")?;
+///
+/// let deadline = Instant::now() + Duration::from_secs(10);
+/// let buffered = with_deadline(deadline, || geom.buffer(10., 8))?;
+/// assert!(buffered.area()? > 300.);
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub fn with_deadline<R>(deadline: Instant, f: impl FnOnce() -> R) -> R {
+    with_interrupt(move || Instant::now() >= deadline, f)
+}
+
+/// Runs `f`, interrupting the GEOS operations it calls on the current thread once `token` is
+/// cancelled, possibly from another thread. See [`with_interrupt`].
+///
+/// Available using the `v3_14_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{with_cancellation, CancellationToken, Error, Geom, Geometry};
+///
+/// let geom = Geometry::new_from_wkt("LINESTRING (0 0, 10 10, 0 10, 10 0)")?;
+/// let token = CancellationToken::new();
+///
+/// assert!(with_cancellation(&token, || geom.buffer(1., 8)).is_ok());
+///
+/// token.cancel();
+/// let result = with_cancellation(&token, || geom.buffer(1., 8));
+/// assert!(matches!(result, Err(Error::Interrupted)));
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub fn with_cancellation<R>(token: &CancellationToken, f: impl FnOnce() -> R) -> R {
+    let token = token.clone();
+    with_interrupt(move || token.is_cancelled(), f)
+}
+
+/// A flag shared between threads to cancel the GEOS operations run with
+/// [`with_cancellation`]. Clones share the same flag.
+///
+/// Available using the `v3_14_0` feature.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a new token, which isn't cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the operations run with this token, or with one of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if [`cancel`](CancellationToken::cancel) has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
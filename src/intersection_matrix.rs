@@ -0,0 +1,213 @@
+use crate::context_handle::with_context;
+use crate::error::{Error, GResult};
+use crate::functions::{errcheck, predicate};
+use crate::{DimensionType, Location};
+use geos_sys::*;
+use std::ffi::CString;
+use std::fmt;
+use std::str::{self, FromStr};
+
+/// A [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) intersection matrix, describing the
+/// topological relationship between two geometries.
+///
+/// Each entry is the dimension of the intersection of a location (interior, boundary or exterior)
+/// of the first geometry with a location of the second one, or `None` if they don't intersect.
+///
+/// # Example
+///
+/// ```
+/// use geos::{DimensionType, Geom, Geometry, Location};
+///
+/// let line = Geometry::new_from_wkt("LINESTRING (0 0, 10 10)")?;
+/// let polygon = Geometry::new_from_wkt("POLYGON ((0 0, 5 0, 5 5, 0 5, 0 0))")?;
+///
+/// let matrix = line.relate_matrix(&polygon)?;
+/// assert_eq!(matrix.to_string(), "101F00212");
+/// assert_eq!(
+///     matrix.get(Location::Interior, Location::Interior),
+///     Some(DimensionType::Curve),
+/// );
+/// assert_eq!(matrix.get(Location::Boundary, Location::Interior), None);
+/// assert!(matrix.is_crosses(DimensionType::Curve, DimensionType::Surface));
+/// # Ok::<(), geos::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntersectionMatrix {
+    matrix: [u8; 9],
+}
+
+impl IntersectionMatrix {
+    /// Returns the dimension of the intersection of location `a` of the first geometry with
+    /// location `b` of the second geometry, or `None` if they don't intersect.
+    pub fn get(&self, a: Location, b: Location) -> Option<DimensionType> {
+        match self.matrix[index(a) * 3 + index(b)] {
+            b'0' => Some(DimensionType::Point),
+            b'1' => Some(DimensionType::Curve),
+            b'2' => Some(DimensionType::Surface),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the matrix matches the DE-9IM `pattern`, like `"T*F**F***"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::IntersectionMatrix;
+    ///
+    /// let matrix: IntersectionMatrix = "212101212".parse()?;
+    /// assert!(matrix.matches("T********")?);
+    /// assert!(!matrix.matches("FF*FF****")?);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn matches(&self, pattern: &str) -> GResult<bool> {
+        let matrix = CString::new(self.to_string())
+            .map_err(|e| Error::GenericError(format!("Conversion to CString failed: {e}")))?;
+        let pattern = CString::new(pattern)
+            .map_err(|e| Error::GenericError(format!("Conversion to CString failed: {e}")))?;
+        with_context(|ctx| unsafe {
+            predicate!(GEOSRelatePatternMatch_r(
+                ctx.as_raw(),
+                matrix.as_ptr(),
+                pattern.as_ptr()
+            ))
+        })
+    }
+
+    /// Returns `true` if the geometries have no point in common.
+    pub fn is_disjoint(&self) -> bool {
+        self.is_empty(Location::Interior, Location::Interior)
+            && self.is_empty(Location::Interior, Location::Boundary)
+            && self.is_empty(Location::Boundary, Location::Interior)
+            && self.is_empty(Location::Boundary, Location::Boundary)
+    }
+
+    /// Returns `true` if the geometries have at least one point in common.
+    pub fn is_intersects(&self) -> bool {
+        !self.is_disjoint()
+    }
+
+    /// Returns `true` if the first geometry is within the second one.
+    pub fn is_within(&self) -> bool {
+        self.is_set(Location::Interior, Location::Interior)
+            && self.is_empty(Location::Interior, Location::Exterior)
+            && self.is_empty(Location::Boundary, Location::Exterior)
+    }
+
+    /// Returns `true` if the first geometry contains the second one.
+    pub fn is_contains(&self) -> bool {
+        self.is_set(Location::Interior, Location::Interior)
+            && self.is_empty(Location::Exterior, Location::Interior)
+            && self.is_empty(Location::Exterior, Location::Boundary)
+    }
+
+    /// Returns `true` if the first geometry covers the second one.
+    pub fn is_covers(&self) -> bool {
+        self.is_intersects()
+            && self.is_empty(Location::Exterior, Location::Interior)
+            && self.is_empty(Location::Exterior, Location::Boundary)
+    }
+
+    /// Returns `true` if the first geometry is covered by the second one.
+    pub fn is_covered_by(&self) -> bool {
+        self.is_intersects()
+            && self.is_empty(Location::Interior, Location::Exterior)
+            && self.is_empty(Location::Boundary, Location::Exterior)
+    }
+
+    /// Returns `true` if the geometries, of dimensions `dim_a` and `dim_b`, are topologically
+    /// equal.
+    pub fn is_equals(&self, dim_a: DimensionType, dim_b: DimensionType) -> bool {
+        dim_a == dim_b
+            && self.is_set(Location::Interior, Location::Interior)
+            && self.is_empty(Location::Interior, Location::Exterior)
+            && self.is_empty(Location::Boundary, Location::Exterior)
+            && self.is_empty(Location::Exterior, Location::Interior)
+            && self.is_empty(Location::Exterior, Location::Boundary)
+    }
+
+    /// Returns `true` if the geometries, of dimensions `dim_a` and `dim_b`, touch: they only
+    /// have boundary points in common.
+    pub fn is_touches(&self, dim_a: DimensionType, dim_b: DimensionType) -> bool {
+        if dim_a == DimensionType::Point && dim_b == DimensionType::Point {
+            return false;
+        }
+        self.is_empty(Location::Interior, Location::Interior)
+            && (self.is_set(Location::Interior, Location::Boundary)
+                || self.is_set(Location::Boundary, Location::Interior)
+                || self.is_set(Location::Boundary, Location::Boundary))
+    }
+
+    /// Returns `true` if the geometries, of dimensions `dim_a` and `dim_b`, cross.
+    pub fn is_crosses(&self, dim_a: DimensionType, dim_b: DimensionType) -> bool {
+        use DimensionType::*;
+
+        let interiors_intersect = self.is_set(Location::Interior, Location::Interior);
+        match (dim_a, dim_b) {
+            (Point, Curve) | (Point, Surface) | (Curve, Surface) => {
+                interiors_intersect && self.is_set(Location::Interior, Location::Exterior)
+            }
+            (Curve, Point) | (Surface, Point) | (Surface, Curve) => {
+                interiors_intersect && self.is_set(Location::Exterior, Location::Interior)
+            }
+            (Curve, Curve) => self.get(Location::Interior, Location::Interior) == Some(Point),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the geometries, of dimensions `dim_a` and `dim_b`, overlap.
+    pub fn is_overlaps(&self, dim_a: DimensionType, dim_b: DimensionType) -> bool {
+        let exteriors_intersect = self.is_set(Location::Interior, Location::Exterior)
+            && self.is_set(Location::Exterior, Location::Interior);
+        match (dim_a, dim_b) {
+            (DimensionType::Point, DimensionType::Point)
+            | (DimensionType::Surface, DimensionType::Surface) => {
+                self.is_set(Location::Interior, Location::Interior) && exteriors_intersect
+            }
+            (DimensionType::Curve, DimensionType::Curve) => {
+                self.get(Location::Interior, Location::Interior) == Some(DimensionType::Curve)
+                    && exteriors_intersect
+            }
+            _ => false,
+        }
+    }
+
+    fn is_set(&self, a: Location, b: Location) -> bool {
+        self.get(a, b).is_some()
+    }
+
+    fn is_empty(&self, a: Location, b: Location) -> bool {
+        self.get(a, b).is_none()
+    }
+}
+
+const fn index(location: Location) -> usize {
+    match location {
+        Location::Interior => 0,
+        Location::Boundary => 1,
+        Location::Exterior => 2,
+    }
+}
+
+impl FromStr for IntersectionMatrix {
+    type Err = Error;
+
+    /// Parses a matrix made of 9 `F`, `0`, `1` or `2` characters, as returned by
+    /// [`relate`](crate::Geom::relate).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let matrix: [u8; 9] = s
+            .as_bytes()
+            .try_into()
+            .ok()
+            .filter(|m: &[u8; 9]| m.iter().all(|c| matches!(c, b'F' | b'0' | b'1' | b'2')))
+            .ok_or_else(|| Error::GenericError(format!("Invalid intersection matrix: {s}")))?;
+        Ok(Self { matrix })
+    }
+}
+
+impl fmt::Display for IntersectionMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Only ASCII characters are accepted when parsing.
+        f.write_str(str::from_utf8(&self.matrix).map_err(|_| fmt::Error)?)
+    }
+}
@@ -13,40 +13,85 @@ pub use wkt;
 pub(crate) mod functions;
 
 pub use buffer_params::{BufferParams, BufferParamsBuilder};
+#[cfg(feature = "v3_8_0")]
+pub use circle::Circle;
+#[cfg(feature = "v3_14_0")]
+pub use cluster::ClusterInfo;
+#[cfg(feature = "v3_14_0")]
+pub use context_handle::InterruptCallback;
 pub use context_handle::{ContextHandle, HandlerCallback};
 pub use coord_seq::CoordSeq;
+#[cfg(feature = "v3_14_0")]
+pub use coverage_clean_params::{CoverageCleanParams, CoverageCleanParamsBuilder};
+#[cfg(feature = "v3_12_0")]
+pub use coverage_validation::CoverageValidation;
 #[cfg(feature = "v3_10_0")]
 pub use enums::MakeValidMethod;
+#[cfg(feature = "v3_14_0")]
+pub use enums::OverlapMergeStrategy;
+#[cfg(feature = "v3_11_0")]
+pub use enums::PolygonHullMode;
 #[cfg(feature = "v3_6_0")]
 pub use enums::Precision;
+#[cfg(feature = "v3_10_0")]
+pub use enums::WkbFlavor;
 pub use enums::{
-    ByteOrder, CapStyle, CoordDimensions, CoordType, DimensionType, GeometryTypes, JoinStyle,
-    Ordinate, Orientation,
+    BoundaryNodeRule, ByteOrder, CapStyle, CoordDimensions, CoordType, DimensionType,
+    GeometryTypes, JoinStyle, Location, Ordinate, Orientation, ValidFlags,
 };
 #[cfg(feature = "v3_7_0")]
 pub use functions::segment_intersection;
 pub use functions::{orientation_index, version};
+#[cfg(feature = "v3_11_0")]
+pub use functions::{sort_by_hilbert_code, sort_by_hilbert_code_key};
 #[cfg(feature = "v3_10_0")]
 pub use geojson_writer::GeoJSONWriter;
 pub use geometry::{ConstGeometry, Geom, Geometry};
+#[cfg(feature = "v3_14_0")]
+pub use grid_spec::GridSpec;
+#[cfg(feature = "v3_14_0")]
+pub use interrupt::{with_cancellation, with_deadline, with_interrupt, CancellationToken};
+pub use intersection_matrix::IntersectionMatrix;
 #[cfg(feature = "v3_10_0")]
 pub use make_valid_params::{MakeValidParams, MakeValidParamsBuilder};
+#[cfg(feature = "v3_10_0")]
+pub use mesh::Mesh;
+pub use polygonize_result::PolygonizeResult;
 pub use prepared_geometry::PreparedGeometry;
 pub use spatial_index::{STRtree, SpatialIndex};
+pub use validity_detail::ValidityDetail;
+pub use wkb_reader::WKBReader;
 pub use wkb_writer::WKBWriter;
+pub use wkt_reader::WKTReader;
 pub use wkt_writer::WKTWriter;
 
 mod buffer_params;
+#[cfg(feature = "v3_8_0")]
+mod circle;
+#[cfg(feature = "v3_14_0")]
+mod cluster;
 mod context_handle;
 mod coord_seq;
+#[cfg(feature = "v3_14_0")]
+mod coverage_clean_params;
+#[cfg(feature = "v3_12_0")]
+mod coverage_validation;
 mod error;
 #[cfg(feature = "geo")]
 pub mod from_geo;
 #[cfg(feature = "json")]
 pub mod from_geojson;
 mod geometry;
+#[cfg(feature = "v3_14_0")]
+mod grid_spec;
+#[cfg(feature = "v3_14_0")]
+mod interrupt;
+mod intersection_matrix;
 #[cfg(feature = "v3_10_0")]
 mod make_valid_params;
+#[cfg(feature = "v3_10_0")]
+mod mesh;
+mod polygonize_result;
 mod prepared_geometry;
 mod spatial_index;
 #[cfg(feature = "geo")]
@@ -62,7 +107,10 @@ mod enums;
 #[cfg(feature = "v3_10_0")]
 mod geojson_writer;
 mod traits;
+mod validity_detail;
+mod wkb_reader;
 mod wkb_writer;
+mod wkt_reader;
 mod wkt_writer;
 
 pub(crate) use traits::{AsRaw, AsRawMut};
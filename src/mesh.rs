@@ -0,0 +1,162 @@
+use crate::error::{Error, GResult};
+use crate::{CoordType, Geom, GeometryTypes};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// An indexed triangle mesh, built from a collection of triangular polygons such as the one
+/// returned by [`delaunay_triangulation`](crate::Geom::delaunay_triangulation) or
+/// [`constrained_delaunay_triangulation`](crate::Geom::constrained_delaunay_triangulation).
+///
+/// Vertices shared by several triangles are only stored once, so the mesh can be handed as is
+/// to a renderer or a finite element solver.
+///
+/// Available using the `v3_10_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry, Mesh};
+///
+/// let square = Geometry::new_from_wkt("POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0))")?;
+/// let triangles = square.constrained_delaunay_triangulation()?;
+///
+/// let mesh = Mesh::new(&triangles, None, true)?;
+/// assert_eq!(mesh.num_vertices(), 4);
+/// assert_eq!(mesh.num_triangles(), 2);
+/// assert_eq!(mesh.vertices.len(), 8);
+/// assert_eq!(mesh.indices.len(), 6);
+///
+/// // The two triangles share the diagonal of the square.
+/// let adjacency = mesh.adjacency.unwrap();
+/// assert_eq!(adjacency.iter().filter(|n| n.is_some()).count(), 2);
+/// # Ok::<(), geos::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mesh {
+    /// The deduplicated vertices, interleaved with the same layout as
+    /// [`CoordSeq::as_buffer`](crate::CoordSeq::as_buffer).
+    pub vertices: Vec<f64>,
+    /// The layout of [`vertices`](Mesh::vertices).
+    pub coord_type: CoordType,
+    /// Three vertex indices per triangle, in the order of the input rings.
+    pub indices: Vec<u32>,
+    /// Three entries per triangle: the `n`-th one is the triangle sharing the edge going from
+    /// its `n`-th vertex to the next one, or `None` if this edge is on the border of the mesh.
+    pub adjacency: Option<Vec<Option<u32>>>,
+}
+
+impl Mesh {
+    /// Builds a mesh from `triangles`, a collection of polygons which must all be triangles.
+    ///
+    /// Vertices are written using `coord_type`, or using the layout of the first triangle if
+    /// `None`. Vertices are merged when all their ordinates are exactly equal. Triangle adjacency
+    /// is only computed if `with_adjacency` is `true`.
+    pub fn new<G: Geom>(
+        triangles: &G,
+        coord_type: Option<CoordType>,
+        with_adjacency: bool,
+    ) -> GResult<Self> {
+        let num_triangles = triangles.get_num_geometries()?;
+        let mut coord_type = coord_type;
+        let mut vertices = Vec::new();
+        let mut indices = Vec::with_capacity(num_triangles * 3);
+        // Vertices are keyed by the bits of their ordinates, the unused ones being left to 0.
+        let mut known_vertices: HashMap<[u64; 4], u32> = HashMap::new();
+
+        for n in 0..num_triangles {
+            let triangle = triangles.get_geometry_n(n)?;
+            if triangle.geometry_type()? != GeometryTypes::Polygon
+                || triangle.get_num_interior_rings()? != 0
+            {
+                return Err(Error::GenericError(format!(
+                    "Geometry {n} of the collection isn't a triangle"
+                )));
+            }
+            let ring = triangle.get_exterior_ring()?.get_coord_seq()?;
+            if ring.size()? != 4 {
+                return Err(Error::GenericError(format!(
+                    "Geometry {n} of the collection isn't a triangle"
+                )));
+            }
+            let coord_type = *coord_type.get_or_insert(ring.coord_type());
+            let dims: u32 = coord_type.into();
+            let buffer = ring.as_buffer(Some(coord_type))?;
+
+            // The last coordinate closes the ring, so it is skipped.
+            for coord in buffer.chunks_exact(dims as usize).take(3) {
+                let mut key = [0u64; 4];
+                for (bits, v) in key.iter_mut().zip(coord) {
+                    // `-0.0` and `0.0` are equal but have different bit patterns.
+                    *bits = if *v == 0. { 0 } else { v.to_bits() };
+                }
+                let index = match known_vertices.get(&key) {
+                    Some(index) => *index,
+                    None => {
+                        let index = u32::try_from(known_vertices.len()).map_err(|_| {
+                            Error::GenericError("Too many vertices for a u32 index".into())
+                        })?;
+                        vertices.extend_from_slice(coord);
+                        known_vertices.insert(key, index);
+                        index
+                    }
+                };
+                indices.push(index);
+            }
+        }
+
+        let adjacency = if with_adjacency {
+            Some(compute_adjacency(&indices)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            vertices,
+            coord_type: coord_type.unwrap_or(CoordType::XY),
+            indices,
+            adjacency,
+        })
+    }
+
+    /// Returns the number of distinct vertices of the mesh.
+    pub fn num_vertices(&self) -> usize {
+        let dims: u32 = self.coord_type.into();
+        self.vertices.len() / dims as usize
+    }
+
+    /// Returns the number of triangles of the mesh.
+    pub fn num_triangles(&self) -> usize {
+        self.indices.len() / 3
+    }
+}
+
+fn compute_adjacency(indices: &[u32]) -> GResult<Vec<Option<u32>>> {
+    let to_index = |triangle: usize| {
+        u32::try_from(triangle)
+            .map_err(|_| Error::GenericError("Too many triangles for a u32 index".into()))
+    };
+    let mut adjacency = vec![None; indices.len()];
+    // Maps an edge to the slot of the first triangle using it, or to `None` once two triangles
+    // have been linked through it.
+    let mut edges: HashMap<(u32, u32), Option<usize>> = HashMap::new();
+
+    for (triangle, vertices) in indices.chunks_exact(3).enumerate() {
+        for edge in 0..3 {
+            let (a, b) = (vertices[edge], vertices[(edge + 1) % 3]);
+            let slot = triangle * 3 + edge;
+            match edges.entry((a.min(b), a.max(b))) {
+                Entry::Vacant(entry) => {
+                    entry.insert(Some(slot));
+                }
+                Entry::Occupied(mut entry) => {
+                    // Edges shared by more than two triangles aren't manifold: only the first
+                    // two are linked.
+                    if let Some(other) = entry.get_mut().take() {
+                        adjacency[slot] = Some(to_index(other / 3)?);
+                        adjacency[other] = Some(to_index(triangle)?);
+                    }
+                }
+            }
+        }
+    }
+    Ok(adjacency)
+}
@@ -0,0 +1,14 @@
+use crate::Geometry;
+
+/// The output of [`polygonize_full`](crate::Geom::polygonize_full).
+#[derive(Debug, Clone)]
+pub struct PolygonizeResult {
+    /// The polygons formed by the input linework, as a `GeometryCollection`.
+    pub polygons: Geometry,
+    /// The edges which have a polygon on both sides but aren't part of any polygon boundary.
+    pub cuts: Option<Geometry>,
+    /// The edges which have one or both ends not connected to any other edge.
+    pub dangles: Option<Geometry>,
+    /// The rings which are closed but don't form a valid polygon, such as bow ties.
+    pub invalid_rings: Option<Geometry>,
+}
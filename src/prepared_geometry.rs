@@ -1,8 +1,18 @@
 use crate::context_handle::with_context;
+#[cfg(feature = "v3_13_0")]
+use crate::error::Error;
 use crate::functions::*;
 use crate::traits::as_raw_impl;
+#[cfg(feature = "v3_9_0")]
+use crate::CoordSeq;
+#[cfg(feature = "v3_14_0")]
+use crate::CoordType;
+#[cfg(feature = "v3_13_0")]
+use crate::IntersectionMatrix;
 use crate::{AsRaw, GResult, Geom};
 use geos_sys::*;
+#[cfg(feature = "v3_13_0")]
+use std::ffi::CString;
 use std::marker::PhantomData;
 use std::ptr::NonNull;
 
@@ -387,6 +397,146 @@ impl<'a> PreparedGeometry<'a> {
             Ok(distance)
         })
     }
+
+    /// Returns the nearest points of `self` and `other`: the first one is on `self` and the
+    /// second one on `other`.
+    ///
+    /// Available using the `v3_9_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")?;
+    /// let prepared_geom = geom1.to_prepared_geom()?;
+    /// let geom2 = Geometry::new_from_wkt("POINT (12 3)")?;
+    ///
+    /// let points = prepared_geom.nearest_points(&geom2)?;
+    /// assert_eq!((points.get_x(0)?, points.get_y(0)?), (10., 3.));
+    /// assert_eq!((points.get_x(1)?, points.get_y(1)?), (12., 3.));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_9_0")]
+    pub fn nearest_points<G: Geom>(&self, other: &G) -> GResult<CoordSeq> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSPreparedNearestPoints_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                other.as_raw(),
+            ))?;
+
+            let mut size = 0;
+            errcheck!(GEOSCoordSeq_getSize_r(
+                ctx.as_raw(),
+                ptr.as_ptr(),
+                &mut size
+            ))?;
+
+            #[cfg(not(feature = "v3_14_0"))]
+            {
+                let mut dims = 0;
+                errcheck!(GEOSCoordSeq_getDimensions_r(
+                    ctx.as_raw(),
+                    ptr.as_ptr(),
+                    &mut dims
+                ))?;
+                Ok(CoordSeq::new_from_raw(ptr, size, dims.try_into()?))
+            }
+            #[cfg(feature = "v3_14_0")]
+            {
+                let has_z = predicate!(GEOSCoordSeq_hasZ_r(ctx.as_raw(), ptr.as_ptr()))?;
+                let has_m = predicate!(GEOSCoordSeq_hasM_r(ctx.as_raw(), ptr.as_ptr()))?;
+
+                Ok(CoordSeq::new_from_raw(
+                    ptr,
+                    size,
+                    CoordType::try_from((has_z, has_m))?,
+                ))
+            }
+        })
+    }
+
+    /// Returns the DE-9IM intersection matrix of `self` and `other`.
+    ///
+    /// Available using the `v3_13_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")?;
+    /// let prepared_geom = geom1.to_prepared_geom()?;
+    /// let geom2 = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+    ///
+    /// assert_eq!(prepared_geom.relate(&geom2)?, "0F2FF1FF2");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_13_0")]
+    pub fn relate<G: Geom>(&self, other: &G) -> GResult<String> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSPreparedRelate_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                other.as_raw()
+            ))?;
+            managed_string(ptr, ctx)
+        })
+    }
+
+    /// Same as [`relate`](PreparedGeometry::relate), but returns a parsed
+    /// [`IntersectionMatrix`].
+    ///
+    /// Available using the `v3_13_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")?;
+    /// let prepared_geom = geom1.to_prepared_geom()?;
+    /// let geom2 = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+    ///
+    /// assert!(prepared_geom.relate_matrix(&geom2)?.is_contains());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_13_0")]
+    pub fn relate_matrix<G: Geom>(&self, other: &G) -> GResult<IntersectionMatrix> {
+        self.relate(other)?.parse()
+    }
+
+    /// Returns `true` if the DE-9IM intersection matrix of `self` and `other` matches `pattern`.
+    ///
+    /// Available using the `v3_13_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry};
+    ///
+    /// let geom1 = Geometry::new_from_wkt("POLYGON((0 0, 10 0, 10 6, 0 6, 0 0))")?;
+    /// let prepared_geom = geom1.to_prepared_geom()?;
+    /// let geom2 = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+    ///
+    /// assert_eq!(prepared_geom.relate_pattern(&geom2, "T*****FF*")?, true);
+    /// assert_eq!(prepared_geom.relate_pattern(&geom2, "FF*FF****")?, false);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_13_0")]
+    pub fn relate_pattern<G: Geom>(&self, other: &G, pattern: &str) -> GResult<bool> {
+        with_context(|ctx| unsafe {
+            let pattern = CString::new(pattern)
+                .map_err(|e| Error::GenericError(format!("Conversion to CString failed: {e}")))?;
+            predicate!(GEOSPreparedRelatePattern_r(
+                ctx.as_raw(),
+                self.as_raw(),
+                other.as_raw(),
+                pattern.as_ptr()
+            ))
+        })
+    }
 }
 
 unsafe impl Send for PreparedGeometry<'_> {}
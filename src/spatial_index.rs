@@ -1,13 +1,11 @@
-use std::ffi::c_void;
+use std::ffi::{c_int, c_void};
 use std::marker::PhantomData;
 use std::ptr::NonNull;
 
 use geos_sys::*;
 
 use crate::context_handle::with_context;
-#[cfg(feature = "v3_12_0")]
-use crate::functions::errcheck;
-use crate::functions::nullcheck;
+use crate::functions::{errcheck, nullcheck, predicate};
 use crate::{AsRaw, AsRawMut, GResult, Geom};
 
 pub trait SpatialIndex<I> {
@@ -21,6 +19,9 @@ pub trait SpatialIndex<I> {
 
 pub struct STRtree<I> {
     pub(crate) ptr: NonNull<GEOSSTRtree>,
+    /// GEOS returns a null pointer both on failure and when the tree is empty, so the items are
+    /// counted to tell them apart.
+    num_items: usize,
     item_type: PhantomData<I>,
 }
 
@@ -30,6 +31,7 @@ impl<I> STRtree<I> {
             let ptr = nullcheck!(GEOSSTRtree_create_r(ctx.as_raw(), node_capacity))?;
             Ok(Self {
                 ptr,
+                num_items: 0,
                 item_type: PhantomData,
             })
         })
@@ -44,6 +46,178 @@ impl<I> STRtree<I> {
             GEOSSTRtree_iterate_r(ctx.as_raw(), self.as_raw_mut(), Some(callback), closure);
         });
     }
+
+    /// Returns the item nearest to `geometry`, or `None` if the tree is empty.
+    ///
+    /// Returns an error if GEOS fails, for example when the search is
+    /// [interrupted](crate::Error::Interrupted).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, STRtree, SpatialIndex};
+    ///
+    /// let mut tree = STRtree::<Geometry>::with_capacity(10)?;
+    /// for wkt in ["POINT (0 0)", "POINT (5 5)", "LINESTRING (10 0, 10 10)"] {
+    ///     tree.insert(&Geometry::new_from_wkt(wkt)?, Geometry::new_from_wkt(wkt)?);
+    /// }
+    ///
+    /// let query = Geometry::new_from_wkt("POINT (9 1)")?;
+    /// let nearest = tree.nearest(&query)?.unwrap();
+    /// assert_eq!(nearest.to_wkt()?, "LINESTRING (10 0, 10 10)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn nearest<G: Geom>(&self, geometry: &G) -> GResult<Option<&I>>
+    where
+        I: Geom,
+    {
+        self.nearest_by(geometry, |item, geometry| {
+            item.distance(geometry).unwrap_or(f64::INFINITY)
+        })
+    }
+
+    /// Returns the item nearest to `geometry`, or `None` if the tree is empty, using `distance`
+    /// to compute the distance between an item and `geometry`. Fails like [`STRtree::nearest`].
+    ///
+    /// The distance must never be smaller than the distance between the envelope of the item and
+    /// the envelope of `geometry`, otherwise the result may not be the nearest item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, STRtree, SpatialIndex};
+    ///
+    /// let facilities = vec![
+    ///     Geometry::new_from_wkt("POINT (0 0)")?,
+    ///     Geometry::new_from_wkt("POINT (5 5)")?,
+    ///     Geometry::new_from_wkt("POINT (10 10)")?,
+    /// ];
+    ///
+    /// let mut tree = STRtree::<usize>::with_capacity(10)?;
+    /// for (index, facility) in facilities.iter().enumerate() {
+    ///     tree.insert(facility, index);
+    /// }
+    ///
+    /// let query = Geometry::new_from_wkt("POINT (6 7)")?;
+    /// let nearest = tree.nearest_by(&query, |index, query| {
+    ///     facilities[*index].distance(query).unwrap_or(f64::INFINITY)
+    /// })?;
+    /// assert_eq!(nearest, Some(&1));
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn nearest_by<G, F>(&self, geometry: &G, distance: F) -> GResult<Option<&I>>
+    where
+        G: Geom,
+        F: FnMut(&I, &G) -> f64,
+    {
+        struct Search<'a, G, F> {
+            geometry: &'a G,
+            distance: F,
+        }
+
+        unsafe extern "C" fn callback<I, G, F>(
+            item1: *const c_void,
+            item2: *const c_void,
+            distance: *mut f64,
+            data: *mut c_void,
+        ) -> c_int
+        where
+            F: FnMut(&I, &G) -> f64,
+        {
+            let search = &mut *data.cast::<Search<G, F>>();
+            // One of the items is the query geometry, the other one is an item of the tree.
+            let query = (search.geometry as *const G).cast::<c_void>();
+            let item = if item1 == query { item2 } else { item1 };
+            *distance = (search.distance)(&*item.cast::<I>(), search.geometry);
+            1
+        }
+
+        let mut search = Search { geometry, distance };
+        with_context(|ctx| unsafe {
+            let item = GEOSSTRtree_nearest_generic_r(
+                ctx.as_raw(),
+                self.as_raw_mut_override(),
+                (geometry as *const G).cast(),
+                geometry.as_raw(),
+                Some(callback::<I, G, F>),
+                (&mut search as *mut Search<G, F>).cast(),
+            );
+            match item.cast::<I>().as_ref() {
+                Some(item) => Ok(Some(item)),
+                None if self.num_items == 0 => Ok(None),
+                None => Err(ctx.take_error("GEOSSTRtree_nearest_generic_r")),
+            }
+        })
+    }
+
+    /// Removes from the tree the first item intersecting the envelope of `geometry` for which
+    /// `predicate` returns `true`, and returns it.
+    ///
+    /// `geometry` should be the one the item was inserted with.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geometry, STRtree, SpatialIndex};
+    ///
+    /// let point = Geometry::new_from_wkt("POINT (5 5)")?;
+    /// let mut tree = STRtree::<String>::with_capacity(10)?;
+    /// tree.insert(&point, "Point".to_owned());
+    ///
+    /// assert_eq!(tree.remove(&point, |item| item == "Line")?, None);
+    /// assert_eq!(tree.remove(&point, |item| item == "Point")?, Some("Point".to_owned()));
+    /// assert_eq!(tree.remove(&point, |item| item == "Point")?, None);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn remove<G, P>(&mut self, geometry: &G, predicate: P) -> GResult<Option<I>>
+    where
+        G: Geom,
+        P: FnMut(&I) -> bool,
+    {
+        struct Search<P> {
+            predicate: P,
+            found: *mut c_void,
+        }
+
+        unsafe extern "C" fn callback<I, P>(item: *mut c_void, data: *mut c_void)
+        where
+            P: FnMut(&I) -> bool,
+        {
+            let search = &mut *data.cast::<Search<P>>();
+            if search.found.is_null() && (search.predicate)(&*item.cast::<I>()) {
+                search.found = item;
+            }
+        }
+
+        let mut search = Search {
+            predicate,
+            found: std::ptr::null_mut(),
+        };
+        with_context(|ctx| unsafe {
+            GEOSSTRtree_query_r(
+                ctx.as_raw(),
+                self.as_raw_mut(),
+                geometry.as_raw(),
+                Some(callback::<I, P>),
+                (&mut search as *mut Search<P>).cast(),
+            );
+            if search.found.is_null() {
+                return Ok(None);
+            }
+            let removed = predicate!(GEOSSTRtree_remove_r(
+                ctx.as_raw(),
+                self.as_raw_mut(),
+                geometry.as_raw(),
+                search.found,
+            ))?;
+            if !removed {
+                return Ok(None);
+            }
+            self.num_items -= 1;
+            // The tree doesn't reference the item anymore, so the box can be taken back.
+            Ok(Some(*Box::from_raw(search.found.cast::<I>())))
+        })
+    }
 }
 
 impl<I> SpatialIndex<I> for STRtree<I> {
@@ -56,6 +230,7 @@ impl<I> SpatialIndex<I> for STRtree<I> {
                 Box::into_raw(Box::new(item)).cast(),
             );
         });
+        self.num_items += 1;
     }
 
     fn query<G: Geom, V: FnMut(&I)>(&self, geometry: &G, visitor: V) {
@@ -175,5 +350,34 @@ mod test {
         });
 
         assert_eq!(items, vec!["Point", "Polygon"].into_iter().collect());
+
+        // Test remove
+
+        assert_eq!(tree.remove(&line, |item| *item == "Point").unwrap(), None);
+        assert_eq!(
+            tree.remove(&point, |item| *item == "Point").unwrap(),
+            Some("Point")
+        );
+
+        items.clear();
+        tree.iterate(|item| {
+            items.insert(*item);
+        });
+
+        assert_eq!(items, vec!["Line", "Polygon"].into_iter().collect());
+    }
+
+    #[test]
+    fn test_strtree_nearest() {
+        let mut tree = STRtree::<Geometry>::with_capacity(10).unwrap();
+        let point = Geometry::new_from_wkt("POINT (5 5)").unwrap();
+        assert!(tree.nearest(&point).unwrap().is_none());
+
+        let line = Geometry::new_from_wkt("LINESTRING (0 0, 10 0)").unwrap();
+        tree.insert(&line, line.clone());
+        assert_eq!(tree.nearest(&point).unwrap(), Some(&line));
+
+        assert!(tree.remove(&line, |_| true).unwrap().is_some());
+        assert!(tree.nearest(&point).unwrap().is_none());
     }
 }
@@ -518,3 +518,255 @@ fn test_make_valid_keep_collapsed_false() {
 
     assert!(valid_geom.is_valid().unwrap());
 }
+
+#[test]
+#[cfg(feature = "v3_14_0")]
+fn test_cluster_out_of_bounds() {
+    let geom = Geometry::new_from_wkt("MULTIPOINT ((0 0), (0 1), (10 10))").unwrap();
+    let clusters = geom.cluster_geometry_distance(2.).unwrap();
+
+    assert_eq!(clusters.get_num_inputs(), 3);
+    assert_eq!(clusters.get_num_clusters(), 2);
+    assert!(clusters.get_cluster_size(2).is_err());
+    assert!(clusters.get_inputs_for_cluster_n(2).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_14_0")]
+fn test_coverage_clean_with_params() {
+    use crate::{CoverageCleanParams, OverlapMergeStrategy};
+
+    let geom = Geometry::new_from_wkt(
+        "GEOMETRYCOLLECTION (POLYGON ((0 0, 10 0, 10 10, 0 10, 0 0)), \
+                             POLYGON ((9 0, 20 0, 20 10, 9 10, 9 0)))",
+    )
+    .unwrap();
+
+    let params = CoverageCleanParams::builder()
+        .snapping_distance(0.)
+        .gap_maximum_width(1.)
+        .overlap_merge_strategy(OverlapMergeStrategy::MinIndex)
+        .build()
+        .unwrap();
+
+    let cleaned = geom
+        .coverage_clean_with_params(&params)
+        .expect("coverage_clean_with_params failed");
+
+    assert_eq!(cleaned.get_num_geometries().unwrap(), 2);
+    assert_eq!(cleaned.get_geometry_n(0).unwrap().area().unwrap(), 100.);
+    assert_eq!(cleaned.get_geometry_n(1).unwrap().area().unwrap(), 100.);
+}
+
+#[test]
+fn test_intersection_matrix() {
+    use crate::{DimensionType, IntersectionMatrix, Location};
+
+    assert!("212101212".parse::<IntersectionMatrix>().is_ok());
+    assert!("21210121".parse::<IntersectionMatrix>().is_err());
+    assert!("T12101212".parse::<IntersectionMatrix>().is_err());
+
+    let matrix: IntersectionMatrix = "FF1FF0102".parse().unwrap();
+    assert_eq!(matrix.to_string(), "FF1FF0102");
+    assert_eq!(matrix.get(Location::Interior, Location::Interior), None);
+    assert_eq!(
+        matrix.get(Location::Exterior, Location::Exterior),
+        Some(DimensionType::Surface)
+    );
+    assert!(matrix.is_disjoint());
+    assert!(!matrix.is_intersects());
+    assert!(!matrix.is_touches(DimensionType::Curve, DimensionType::Curve));
+}
+
+#[test]
+#[cfg(feature = "v3_11_0")]
+fn test_sort_by_hilbert_code() {
+    use crate::sort_by_hilbert_code_key;
+
+    let mut records = [7, 2, 9, 0, 5, 3, 8, 1, 6, 4]
+        .into_iter()
+        .map(|i| {
+            let wkt = format!("POINT ({} {})", i * 10, (i % 3) * 10);
+            (Geometry::new_from_wkt(&wkt).unwrap(), i)
+        })
+        .collect::<Vec<_>>();
+    let extent = Geometry::create_rectangle(0., 0., 90., 20.).unwrap();
+
+    sort_by_hilbert_code_key(&mut records, 8, |(geom, _)| geom).unwrap();
+
+    let mut ids = records.iter().map(|(_, i)| *i).collect::<Vec<_>>();
+    let codes = records
+        .iter()
+        .map(|(geom, _)| geom.hilbert_code(&extent, 8).unwrap())
+        .collect::<Vec<_>>();
+    assert!(codes.windows(2).all(|w| w[0] <= w[1]));
+    ids.sort();
+    assert_eq!(ids, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+#[cfg(feature = "v3_13_0")]
+fn test_create_curves_type_checks() {
+    let point = Geometry::new_from_wkt("POINT (0 0)").unwrap();
+    let line = Geometry::new_from_wkt("LINESTRING (0 0, 1 0)").unwrap();
+    assert!(Geometry::create_compound_curve(vec![line, point]).is_err());
+
+    let shell = Geometry::new_from_wkt("LINESTRING (0 0, 1 0, 1 1, 0 0)").unwrap();
+    assert!(Geometry::create_curve_polygon(shell, vec![]).is_err());
+
+    let shell = Geometry::new_from_wkt("CIRCULARSTRING (0 0, 4 0, 0 0)").unwrap();
+    let hole = Geometry::new_from_wkt("POINT (2 0)").unwrap();
+    assert!(Geometry::create_curve_polygon(shell, vec![hole]).is_err());
+}
+
+#[test]
+fn test_user_data_freed_on_drop() {
+    use std::sync::Arc;
+
+    let data = Arc::new(42);
+
+    let mut point = Geometry::new_from_wkt("POINT (1 1)").unwrap();
+    point.set_user_data(Arc::clone(&data));
+    point.set_user_data(Arc::clone(&data));
+    assert_eq!(Arc::strong_count(&data), 2);
+    drop(point);
+    assert_eq!(Arc::strong_count(&data), 1);
+
+    let mut ring = Geometry::new_from_wkt("LINEARRING (0 0, 1 0, 1 1, 0 0)").unwrap();
+    ring.set_user_data(Arc::clone(&data));
+    let mut polygon = Geometry::create_polygon(ring, vec![]).unwrap();
+    polygon.set_user_data(Arc::clone(&data));
+    let mut collection = Geometry::create_multipolygon(vec![polygon]).unwrap();
+    collection.set_user_data(Arc::clone(&data));
+    assert_eq!(Arc::strong_count(&data), 4);
+
+    let cloned = Geom::clone(&collection).unwrap();
+    assert!(cloned.user_data::<Arc<i32>>().is_none());
+    assert_eq!(
+        collection
+            .get_geometry_n(0)
+            .unwrap()
+            .user_data::<Arc<i32>>()
+            .map(|d| **d),
+        Some(42)
+    );
+    drop(collection);
+    assert_eq!(Arc::strong_count(&data), 1);
+
+    // Only the ring carries data, the geometries built from it must still free it.
+    let mut ring = Geometry::new_from_wkt("LINEARRING (0 0, 1 0, 1 1, 0 0)").unwrap();
+    ring.set_user_data(Arc::clone(&data));
+    let polygon = Geometry::create_polygon(ring, vec![]).unwrap();
+    let collection = Geometry::create_geometry_collection(vec![polygon]).unwrap();
+    assert_eq!(Arc::strong_count(&data), 2);
+    drop(collection);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+#[cfg(feature = "v3_13_0")]
+fn test_user_data_freed_on_curve_drop() {
+    use std::sync::Arc;
+
+    let data = Arc::new(42);
+
+    let mut line = Geometry::new_from_wkt("LINESTRING (4 0, 0 0)").unwrap();
+    line.set_user_data(Arc::clone(&data));
+    let mut arc = Geometry::new_from_wkt("CIRCULARSTRING (0 0, 2 2, 4 0)").unwrap();
+    arc.set_user_data(Arc::clone(&data));
+    let mut curve = Geometry::create_compound_curve(vec![arc, line]).unwrap();
+    curve.set_user_data(Arc::clone(&data));
+    assert_eq!(Arc::strong_count(&data), 4);
+    drop(curve);
+    assert_eq!(Arc::strong_count(&data), 1);
+
+    let mut line = Geometry::new_from_wkt("LINESTRING (4 0, 0 0)").unwrap();
+    line.set_user_data(Arc::clone(&data));
+    let arc = Geometry::new_from_wkt("CIRCULARSTRING (0 0, 2 2, 4 0)").unwrap();
+    let shell = Geometry::create_compound_curve(vec![arc, line]).unwrap();
+    let mut polygon = Geometry::create_curve_polygon(shell, vec![]).unwrap();
+    polygon.set_user_data(Arc::clone(&data));
+    assert_eq!(Arc::strong_count(&data), 3);
+    drop(polygon);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+#[cfg(feature = "v3_12_0")]
+fn test_into_parts() {
+    let point = Geometry::new_from_wkt("POINT (1 1)").unwrap();
+    assert!(point.into_parts().is_err());
+
+    let empty = Geometry::new_from_wkt("MULTIPOINT EMPTY").unwrap();
+    assert!(empty.into_parts().unwrap().is_empty());
+
+    let mut point1 = Geometry::new_from_wkt("POINT (1 1)").unwrap();
+    point1.set_user_data("first");
+    let mut point2 = Geometry::new_from_wkt("POINT (2 2)").unwrap();
+    point2.set_user_data("second");
+    let points = Geometry::create_multipoint(vec![point1, point2]).unwrap();
+
+    let parts = points.into_parts().unwrap();
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[0].to_wkt().unwrap(), "POINT (1 1)");
+    assert_eq!(parts[1].user_data::<&str>(), Some(&"second"));
+}
+
+#[test]
+#[cfg(feature = "v3_10_0")]
+fn test_mesh() {
+    use crate::{CoordType, Mesh};
+
+    // The second triangle uses `-0` for a vertex shared with the first one.
+    let triangles = Geometry::new_from_wkt(
+        "GEOMETRYCOLLECTION Z (POLYGON Z ((0 0 1, 1 0 1, 0 1 1, 0 0 1)), \
+                              POLYGON Z ((1 0 1, 1 1 1, -0 1 1, 1 0 1)))",
+    )
+    .unwrap();
+
+    let mesh = Mesh::new(&triangles, None, true).unwrap();
+    assert_eq!(mesh.coord_type, CoordType::XYZ);
+    assert_eq!(mesh.num_vertices(), 4);
+    assert_eq!(mesh.vertices.len(), 12);
+    assert_eq!(mesh.indices, vec![0, 1, 2, 1, 3, 2]);
+    assert_eq!(
+        mesh.adjacency.unwrap(),
+        vec![None, Some(1), None, None, None, Some(0)]
+    );
+
+    let mesh = Mesh::new(&triangles, Some(CoordType::XY), false).unwrap();
+    assert_eq!(mesh.coord_type, CoordType::XY);
+    assert_eq!(mesh.vertices, vec![0., 0., 1., 0., 0., 1., 1., 1.]);
+    assert!(mesh.adjacency.is_none());
+
+    let not_triangles = Geometry::new_from_wkt("GEOMETRYCOLLECTION (POINT (0 0))").unwrap();
+    assert!(Mesh::new(&not_triangles, None, false).is_err());
+}
+
+#[test]
+#[cfg(feature = "v3_14_0")]
+fn test_nested_interrupt_scopes() {
+    use crate::{with_cancellation, with_deadline, with_interrupt, CancellationToken, Error};
+    use std::time::Instant;
+
+    let geom = Geometry::new_from_wkt("LINESTRING (0 0, 10 10, 0 10, 10 0)").unwrap();
+    let token = CancellationToken::new();
+
+    // The deadline of the outer scope still applies in the inner one.
+    let result = with_deadline(Instant::now(), || {
+        with_cancellation(&token, || geom.buffer(1., 8))
+    });
+    assert!(matches!(result, Err(Error::Interrupted)));
+
+    token.cancel();
+    let result = with_cancellation(&token, || {
+        let inner = with_interrupt(|| false, || geom.buffer(1., 8));
+        assert!(matches!(inner, Err(Error::Interrupted)));
+        geom.buffer(1., 8)
+    });
+    assert!(matches!(result, Err(Error::Interrupted)));
+
+    // Once the scopes are left, nothing is interrupted anymore.
+    assert!(with_interrupt(|| false, || geom.buffer(1., 8)).is_ok());
+    assert!(geom.buffer(1., 8).is_ok());
+}
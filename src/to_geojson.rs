@@ -101,6 +101,31 @@ fn to_geojson<T: Geom>(other: T) -> Result<Geometry, Error> {
     }
 }
 
+/// Converts `geom` into a [`geojson::Geometry`], following the
+/// [RFC 7946](https://www.rfc-editor.org/rfc/rfc7946#section-3.1.6) winding order: exterior
+/// rings are counter-clockwise and interior rings are clockwise.
+///
+/// Available using the `v3_12_0` feature.
+///
+/// # Example
+///
+/// ```
+/// use geos::to_geojson::to_geojson_rfc7946;
+/// use geos::Geometry;
+/// use geojson::Value;
+///
+/// let clockwise = Geometry::new_from_wkt("POLYGON ((0 0, 0 1, 1 1, 1 0, 0 0))")?;
+/// let geojson = to_geojson_rfc7946(&clockwise)?;
+///
+/// let Value::Polygon(rings) = geojson.value else { unreachable!() };
+/// assert_eq!(rings[0][1], vec![1., 0.]);
+/// # Ok::<(), geos::Error>(())
+/// ```
+#[cfg(feature = "v3_12_0")]
+pub fn to_geojson_rfc7946<T: Geom>(geom: &T) -> GResult<Geometry> {
+    to_geojson(geom.orient_polygons(false)?)
+}
+
 impl TryFrom<GGeometry> for Geometry {
     type Error = Error;
 
@@ -230,6 +255,35 @@ mod test {
         assert_eq!(geojson_polygon, expected_polygon);
     }
 
+    #[test]
+    #[cfg(feature = "v3_12_0")]
+    fn geom_to_geojson_rfc7946() {
+        use super::to_geojson_rfc7946;
+
+        let poly = "POLYGON((0 0, 0 3, 3 3, 3 0, 0 0), (1 1, 2 1, 2 2, 1 2, 1 1))";
+        let poly = GGeometry::new_from_wkt(poly).unwrap();
+
+        let geojson_polygon = to_geojson_rfc7946(&poly).unwrap();
+
+        let expected_polygon = Geometry::new(Value::Polygon(vec![
+            vec![
+                vec![0., 0.],
+                vec![3., 0.],
+                vec![3., 3.],
+                vec![0., 3.],
+                vec![0., 0.],
+            ],
+            vec![
+                vec![1., 1.],
+                vec![1., 2.],
+                vec![2., 2.],
+                vec![2., 1.],
+                vec![1., 1.],
+            ],
+        ]));
+        assert_eq!(geojson_polygon, expected_polygon);
+    }
+
     #[test]
     fn geom_to_geojson_geometry_collection() {
         let gc = "GEOMETRYCOLLECTION(POINT(1 1), LINESTRING(1 1, 2 2))";
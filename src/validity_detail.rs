@@ -0,0 +1,11 @@
+use crate::Geometry;
+
+/// Describes why a [`Geometry`] is invalid, as returned by
+/// [`is_valid_detail`](crate::Geom::is_valid_detail).
+#[derive(Debug, Clone)]
+pub struct ValidityDetail {
+    /// The explanation of why the geometry is invalid.
+    pub reason: String,
+    /// The point where the problem was detected, if any.
+    pub location: Option<Geometry>,
+}
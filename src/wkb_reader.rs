@@ -0,0 +1,148 @@
+use crate::context_handle::with_context;
+use crate::functions::nullcheck;
+use crate::traits::as_raw_mut_impl;
+use crate::{AsRaw, AsRawMut, GResult, Geometry};
+
+use geos_sys::*;
+use std::ptr::NonNull;
+
+/// The `WKBReader` type is used to create [`Geometry`] from `HEX` or `WKB` formatted input. It
+/// can be reused to read many geometries.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, Geometry, WKBReader};
+///
+/// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+/// let mut reader = WKBReader::new()?;
+///
+/// // Input as WKB
+/// let geom = reader.read_wkb(&point_geom.to_wkb()?)?;
+/// assert_eq!(geom.to_wkt()?, "POINT (2.5 2.5)");
+///
+/// // Input as HEX
+/// let geom = reader.read_hex(&point_geom.to_hex()?)?;
+/// assert_eq!(geom.to_wkt()?, "POINT (2.5 2.5)");
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub struct WKBReader {
+    ptr: NonNull<GEOSWKBReader>,
+}
+
+impl WKBReader {
+    /// Creates a new `WKBReader` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, Geometry, WKBReader};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT (2.5 2.5)")?;
+    /// let mut reader = WKBReader::new()?;
+    ///
+    /// let geom = reader.read_wkb(&point_geom.to_wkb()?)?;
+    /// assert_eq!(geom.to_wkt()?, "POINT (2.5 2.5)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn new() -> GResult<Self> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSWKBReader_create_r(ctx.as_raw()))?;
+            Ok(Self { ptr })
+        })
+    }
+
+    /// Reads a [`Geometry`] from the given WKB buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, WKBReader};
+    ///
+    /// let mut reader = WKBReader::new()?;
+    ///
+    /// let wkb = [
+    ///     1u8, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 64, 0, 0, 0, 0, 0, 0, 4, 64,
+    /// ];
+    /// let geom = reader.read_wkb(&wkb)?;
+    /// assert_eq!(geom.to_wkt()?, "POINT (2.5 2.5)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn read_wkb(&mut self, wkb: &[u8]) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSWKBReader_read_r(
+                ctx.as_raw(),
+                self.as_raw_mut(),
+                wkb.as_ptr(),
+                wkb.len(),
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Reads a [`Geometry`] from the given HEX buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, WKBReader};
+    ///
+    /// let mut reader = WKBReader::new()?;
+    ///
+    /// let geom = reader.read_hex(b"010100000000000000000004400000000000000440")?;
+    /// assert_eq!(geom.to_wkt()?, "POINT (2.5 2.5)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn read_hex(&mut self, hex: &[u8]) -> GResult<Geometry> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSWKBReader_readHEX_r(
+                ctx.as_raw(),
+                self.as_raw_mut(),
+                hex.as_ptr(),
+                hex.len(),
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Sets whether the reader should try to repair the structure of the geometries it reads,
+    /// for example by closing unclosed rings. By default, it is `false`.
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, WKBReader};
+    ///
+    /// // A polygon whose ring isn't closed: (0 0, 1 0, 1 1).
+    /// let hex = b"01030000000100000003000000\
+    ///     00000000000000000000000000000000\
+    ///     000000000000F03F0000000000000000\
+    ///     000000000000F03F000000000000F03F";
+    /// let mut reader = WKBReader::new()?;
+    /// assert!(reader.read_hex(hex).is_err());
+    ///
+    /// reader.set_fix_structure(true);
+    /// let geom = reader.read_hex(hex)?;
+    /// assert_eq!(geom.to_wkt()?, "POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_11_0")]
+    pub fn set_fix_structure(&mut self, fix_structure: bool) {
+        with_context(|ctx| unsafe {
+            GEOSWKBReader_setFixStructure_r(ctx.as_raw(), self.as_raw_mut(), fix_structure.into());
+        });
+    }
+}
+
+unsafe impl Send for WKBReader {}
+unsafe impl Sync for WKBReader {}
+
+impl Drop for WKBReader {
+    fn drop(&mut self) {
+        with_context(|ctx| unsafe { GEOSWKBReader_destroy_r(ctx.as_raw(), self.as_raw_mut()) });
+    }
+}
+
+as_raw_mut_impl!(WKBReader, GEOSWKBReader);
@@ -1,4 +1,6 @@
 use crate::context_handle::with_context;
+#[cfg(feature = "v3_10_0")]
+use crate::enums::WkbFlavor;
 use crate::enums::{ByteOrder, CoordDimensions};
 use crate::functions::{errcheck, managed_vec, nullcheck, predicate};
 use crate::traits::as_raw_mut_impl;
@@ -212,6 +214,62 @@ impl WKBWriter {
         });
     }
 
+    /// Gets the WKB flavor.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{WKBWriter, WkbFlavor};
+    ///
+    /// let mut writer = WKBWriter::new()?;
+    /// assert_eq!(writer.get_flavor()?, WkbFlavor::Extended);
+    ///
+    /// writer.set_flavor(WkbFlavor::Iso);
+    /// assert_eq!(writer.get_flavor()?, WkbFlavor::Iso);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_10_0")]
+    pub fn get_flavor(&self) -> GResult<WkbFlavor> {
+        with_context(|ctx| unsafe {
+            let out = GEOSWKBWriter_getFlavor_r(ctx.as_raw(), self.as_raw());
+            WkbFlavor::try_from(out)
+        })
+    }
+
+    /// Sets the WKB flavor. By default, it is [`WkbFlavor::Extended`].
+    ///
+    /// The flavor changes how the Z and M dimensions are encoded in the geometry type.
+    ///
+    /// Available using the `v3_10_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{ByteOrder, CoordDimensions, Geometry, WKBWriter, WkbFlavor};
+    ///
+    /// let point_geom = Geometry::new_from_wkt("POINT Z (1 2 3)")?;
+    /// let mut writer = WKBWriter::new()?;
+    /// writer.set_wkb_byte_order(ByteOrder::LittleEndian);
+    /// writer.set_output_dimension(CoordDimensions::ThreeD);
+    ///
+    /// // Extended WKB flags the Z dimension with a high bit: 0x80000001.
+    /// writer.set_flavor(WkbFlavor::Extended);
+    /// assert_eq!(writer.write_wkb(&point_geom)?[1..5], [1, 0, 0, 0x80]);
+    ///
+    /// // ISO WKB uses the 1001 geometry type.
+    /// writer.set_flavor(WkbFlavor::Iso);
+    /// assert_eq!(writer.write_wkb(&point_geom)?[1..5], [0xE9, 0x03, 0, 0]);
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_10_0")]
+    pub fn set_flavor(&mut self, flavor: WkbFlavor) {
+        with_context(|ctx| unsafe {
+            GEOSWKBWriter_setFlavor_r(ctx.as_raw(), self.as_raw_mut(), flavor.into());
+        });
+    }
+
     /// Gets if output will include SRID.
     ///
     /// # Example
@@ -0,0 +1,112 @@
+use crate::context_handle::with_context;
+use crate::error::Error;
+use crate::functions::nullcheck;
+use crate::traits::as_raw_mut_impl;
+use crate::{AsRaw, AsRawMut, GResult, Geometry};
+use geos_sys::*;
+use std::ffi::CString;
+use std::ptr::NonNull;
+
+/// The `WKTReader` type is used to create [`Geometry`] from `WKT` formatted input. It can be
+/// reused to read many geometries.
+///
+/// # Example
+///
+/// ```
+/// use geos::{Geom, WKTReader};
+///
+/// let mut reader = WKTReader::new()?;
+/// let point_geom = reader.read("POINT (2.5 2.5)")?;
+///
+/// assert_eq!(point_geom.to_wkt()?, "POINT (2.5 2.5)");
+/// # Ok::<(), geos::Error>(())
+/// ```
+pub struct WKTReader {
+    ptr: NonNull<GEOSWKTReader>,
+}
+
+impl WKTReader {
+    /// Creates a new `WKTReader` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, WKTReader};
+    ///
+    /// let mut reader = WKTReader::new()?;
+    /// let point_geom = reader.read("POINT (2.5 2.5)")?;
+    ///
+    /// assert_eq!(point_geom.to_wkt()?, "POINT (2.5 2.5)");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn new() -> GResult<Self> {
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSWKTReader_create_r(ctx.as_raw()))?;
+            Ok(Self { ptr })
+        })
+    }
+
+    /// Reads a [`Geometry`] from the given `wkt` string.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, WKTReader};
+    ///
+    /// let mut reader = WKTReader::new()?;
+    ///
+    /// let line_geom = reader.read("LINESTRING (0 0, 1 1)")?;
+    /// assert_eq!(line_geom.get_num_points()?, 2);
+    ///
+    /// assert!(reader.read("LINESTRING (0 0,").is_err());
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    pub fn read(&mut self, wkt: &str) -> GResult<Geometry> {
+        let c_str = CString::new(wkt)
+            .map_err(|e| Error::GenericError(format!("Conversion to CString failed with {e}")))?;
+        with_context(|ctx| unsafe {
+            let ptr = nullcheck!(GEOSWKTReader_read_r(
+                ctx.as_raw(),
+                self.as_raw_mut(),
+                c_str.as_ptr()
+            ))?;
+            Ok(Geometry::new_from_raw(ptr))
+        })
+    }
+
+    /// Sets whether the reader should try to repair the structure of the geometries it reads,
+    /// for example by closing unclosed rings. By default, it is `false`.
+    ///
+    /// Available using the `v3_11_0` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geos::{Geom, WKTReader};
+    ///
+    /// let mut reader = WKTReader::new()?;
+    /// assert!(reader.read("POLYGON ((0 0, 1 0, 1 1))").is_err());
+    ///
+    /// reader.set_fix_structure(true);
+    /// let polygon_geom = reader.read("POLYGON ((0 0, 1 0, 1 1))")?;
+    /// assert_eq!(polygon_geom.to_wkt()?, "POLYGON ((0 0, 1 0, 1 1, 0 0))");
+    /// # Ok::<(), geos::Error>(())
+    /// ```
+    #[cfg(feature = "v3_11_0")]
+    pub fn set_fix_structure(&mut self, fix_structure: bool) {
+        with_context(|ctx| unsafe {
+            GEOSWKTReader_setFixStructure_r(ctx.as_raw(), self.as_raw_mut(), fix_structure.into());
+        });
+    }
+}
+
+unsafe impl Send for WKTReader {}
+unsafe impl Sync for WKTReader {}
+
+impl Drop for WKTReader {
+    fn drop(&mut self) {
+        with_context(|ctx| unsafe { GEOSWKTReader_destroy_r(ctx.as_raw(), self.as_raw_mut()) });
+    }
+}
+
+as_raw_mut_impl!(WKTReader, GEOSWKTReader);